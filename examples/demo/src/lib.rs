@@ -37,8 +37,8 @@ impl App {
             theme: theme.clone(),
             button,
             frame: Frame::default().with_theme(theme.clone()),
-            btn_frame: Frame::default().with_theme(theme.clone()),
-            dice: Dice::new(),
+            btn_frame: Frame::default().with_theme(theme.clone()).with_depth(1),
+            dice: Dice::new().with_sound(true),
         }
     }
 }
@@ -96,18 +96,13 @@ impl Application<Msg> for App {
                     )]).map_msg(|fmsg|Msg::FrameMsg(Box::new(fmsg)))}
                 */
                 </div>
-                <div>
-                /*
-                    {self.btn_frame.view([
-                        self.dice.view([
-                            img([src("assets/moon.jpg"),
-                                style! {display:"block", width: px(500), height: px(500)}
-                            ],[])
-                            ])
-                        .map_msg(|dmsg|Msg::DiceMsg(Box::new(dmsg)))
-                     ]).map_msg(|fmsg|Msg::BtnFrameMsg(Box::new(fmsg)))}
-                */
-                </div>
+                <sfui-frame
+                    theme-primary=&self.theme.primary_color
+                    theme-background=&self.theme.background_color>
+                        {self.btn_frame.view([
+                            text("This frame is nested one level deep, its corners are inset")
+                        ]).map_msg(|fmsg|Msg::BtnFrameMsg(Box::new(fmsg)))}
+                </sfui-frame>
                 <sfui-frame
                     theme-primary=&self.theme.primary_color
                     theme-background=&self.theme.background_color>
@@ -140,6 +135,7 @@ impl Application<Msg> for App {
             self.theme.style(),
             self.button.style(),
             self.frame.style(),
+            self.btn_frame.style(),
             self.dice.style(),
         ]
         .concat()
@@ -161,7 +157,7 @@ impl Application<Msg> for App {
             }
             Msg::BtnFrameMsg(fmsg) => {
                 let effects = self.btn_frame.update(*fmsg);
-                Cmd::from(effects.localize(|fmsg| Msg::FrameMsg(Box::new(fmsg))))
+                Cmd::from(effects.localize(|fmsg| Msg::BtnFrameMsg(Box::new(fmsg))))
             }
             Msg::DiceMsg(dmsg) => {
                 let effects = self
@@ -1,5 +1,5 @@
 use sfui::card::Card;
-use sfui::frame::Frame;
+use sfui::frame::{Frame, FrameGrid};
 use sfui::sauron;
 use sfui::sauron::*;
 use sfui::Theme;
@@ -8,6 +8,7 @@ struct App {
     theme: Theme,
     frame: Frame<Msg>,
     card: Card<Msg>,
+    grid: FrameGrid,
 }
 
 impl App {
@@ -16,6 +17,7 @@ impl App {
         Self {
             frame: Frame::default().with_theme(theme.clone()),
             card: Card::default().with_theme(theme.clone()),
+            grid: FrameGrid::new(2).with_shared_borders(),
             theme,
         }
     }
@@ -29,22 +31,62 @@ impl Application<Msg> for App {
     }
 
     fn view(&self) -> Node<Msg> {
+        let panels = ["Power", "Shields", "Sensors", "Comms"].map(|heading| {
+            node! {
+                <sfui-frame heading=heading theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                    {text(format!("{} nominal", heading))}
+                </sfui-frame>
+            }
+        });
         node! {
-            <sfui-frame theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+            <sfui-frame heading="System" background="scanlines" theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                <div slot="header">This is the header of sfui-frame</div>
                 This is inside sfui-frame
                 <button>This is a button</button>
                 <br/>
                 <p>
                     This is a paragraph
                 </p>
+                <div slot="footer">This is the footer of sfui-frame</div>
             </sfui-frame>
-            <sfui-card theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+            <sfui-frame shape="chipped-diagonal" assembly="visible" theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                This frame outline is drawn in svg
+            </sfui-frame>
+            <sfui-frame feature="hud" dimension="large" background="grid" theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                A hud frame with large corners
+            </sfui-frame>
+            <sfui-frame feature="bracket-only" corner-top-left="triangle" corner-bottom-right="blink" theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                Brackets only
+            </sfui-frame>
+            <sfui-frame heading="Outer" theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                <sfui-frame theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                    The corners of this nested frame are inset
+                </sfui-frame>
+            </sfui-frame>
+            {self.grid.view(panels)}
+            <sfui-frame selectable theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                Click or press enter to select this frame
+            </sfui-frame>
+            <sfui-card heading="Grill Chicken Chop" subtitle="Main course" description="chicken, egg, mushroom, salad"
+                media="assets/img/pos/product-1.jpg" media-aspect-ratio="4 / 3" media-reveal="dice" price="$10.99" meta="In stock" selectable expandable
+                theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+                <p>This is projected into the card body</p>
+                <p slot="expandable">Served with rice and a side of soup</p>
+                <button slot="actions">Order</button>
             </sfui-card>
+            <sfui-product-card name="Grill Chicken Chop" description="chicken, egg, mushroom, salad" price="$10.99"
+                image="assets/img/pos/product-1.jpg"
+                theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+            </sfui-product-card>
+            <sfui-icon-card heading="Warning" text="Reactor temperature is rising" status="warning"
+                icon="M1 21h22L12 2 1 21zm12-3h-2v-2h2v2zm0-4h-2v-4h2v4z"
+                theme-primary=&self.theme.primary_color theme-background=&self.theme.background_color>
+            </sfui-icon-card>
         }
     }
 
     fn style(&self) -> Vec<String> {
-        vec![self.frame.style(), self.theme.style()].concat()
+        vec![self.frame.style(), self.grid.style(), self.theme.style()].concat()
     }
 }
 
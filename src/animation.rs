@@ -0,0 +1,142 @@
+//! Animations driven by the browser's animation frames, which report back to the components
+//! through dom events, the same way as the observers.
+use sauron::wasm_bindgen::JsCast;
+use sauron::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// the event dispatched on the animated element on each animation frame
+pub const ANIMATION_FRAME_EVENT: &str = "sfui-animation-frame";
+
+/// Dispatches [`ANIMATION_FRAME_EVENT`] on an element on each animation frame and keeps track
+/// of the time elapsed since it started.
+/// The loop is paused while the page is hidden, and that time is not counted as elapsed.
+/// The loop is stopped when dropped.
+pub struct AnimationLoop {
+    state: Rc<RefCell<LoopState>>,
+    _visibility_callback: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+struct LoopState {
+    target: web_sys::Element,
+    /// the id of the pending animation frame request
+    request_id: Option<i32>,
+    /// the timestamp of the last frame, reset when paused so the hidden time is not counted
+    last_timestamp: Option<f64>,
+    elapsed: f64,
+    frame_callback: Option<Closure<dyn FnMut(f64)>>,
+}
+
+impl AnimationLoop {
+    pub fn start(element: &web_sys::Element) -> Self {
+        let state = Rc::new(RefCell::new(LoopState {
+            target: element.clone(),
+            request_id: None,
+            last_timestamp: None,
+            elapsed: 0.0,
+            frame_callback: None,
+        }));
+
+        let weak = Rc::downgrade(&state);
+        let frame_callback: Closure<dyn FnMut(f64)> = Closure::new(move |timestamp: f64| {
+            let Some(state) = weak.upgrade() else {
+                return;
+            };
+            let target = {
+                let mut state = state.borrow_mut();
+                if let Some(last_timestamp) = state.last_timestamp {
+                    state.elapsed += (timestamp - last_timestamp).max(0.0);
+                }
+                state.last_timestamp = Some(timestamp);
+                state.request_id = None;
+                state.target.clone()
+            };
+            if !is_page_hidden() {
+                request_frame(&state);
+            }
+            // the listener may drop this loop, so the state is not borrowed while dispatching
+            let event = web_sys::Event::new(ANIMATION_FRAME_EVENT).expect("must create event");
+            target.dispatch_event(&event).expect("must dispatch event");
+        });
+        state.borrow_mut().frame_callback = Some(frame_callback);
+
+        let weak = Rc::downgrade(&state);
+        let visibility_callback: Closure<dyn FnMut(web_sys::Event)> = Closure::new(move |_event| {
+            let Some(state) = weak.upgrade() else {
+                return;
+            };
+            if is_page_hidden() {
+                cancel_frame(&state);
+                state.borrow_mut().last_timestamp = None;
+            } else if state.borrow().request_id.is_none() {
+                request_frame(&state);
+            }
+        });
+        document()
+            .add_event_listener_with_callback(
+                "visibilitychange",
+                visibility_callback.as_ref().unchecked_ref(),
+            )
+            .expect("must add visibilitychange listener");
+
+        if !is_page_hidden() {
+            request_frame(&state);
+        }
+
+        Self {
+            state,
+            _visibility_callback: visibility_callback,
+        }
+    }
+
+    /// the time in ms the loop has been running, excluding the time the page was hidden
+    pub fn elapsed(&self) -> f64 {
+        self.state.borrow().elapsed
+    }
+}
+
+impl Drop for AnimationLoop {
+    fn drop(&mut self) {
+        cancel_frame(&self.state);
+        document()
+            .remove_event_listener_with_callback(
+                "visibilitychange",
+                self._visibility_callback.as_ref().unchecked_ref(),
+            )
+            .expect("must remove visibilitychange listener");
+    }
+}
+
+fn request_frame(state: &Rc<RefCell<LoopState>>) {
+    let request_id = {
+        let state = state.borrow();
+        let callback = state
+            .frame_callback
+            .as_ref()
+            .expect("must have a frame callback");
+        window()
+            .request_animation_frame(callback.as_ref().unchecked_ref())
+            .expect("must request animation frame")
+    };
+    state.borrow_mut().request_id = Some(request_id);
+}
+
+fn cancel_frame(state: &Rc<RefCell<LoopState>>) {
+    if let Some(request_id) = state.borrow_mut().request_id.take() {
+        window()
+            .cancel_animation_frame(request_id)
+            .expect("must cancel animation frame");
+    }
+}
+
+fn is_page_hidden() -> bool {
+    document().hidden()
+}
+
+fn window() -> web_sys::Window {
+    web_sys::window().expect("must have a window")
+}
+
+fn document() -> web_sys::Document {
+    window().document().expect("must have a document")
+}
@@ -1,4 +1,8 @@
-use crate::frame::{self, Dimension, Frame};
+use crate::custom_element::{
+    register_custom_element, AttributeDef, AttributeValues, CustomElement, DeclaredAttributes,
+};
+use crate::frame::{self, Assembly, AssemblyTrigger, Dimension, Frame};
+use crate::scope::Scope;
 use crate::Status;
 use crate::Theme;
 use async_trait::async_trait;
@@ -17,7 +21,7 @@ use wasm_bindgen_futures::JsFuture;
 use web_sys::HtmlAudioElement;
 use web_sys::MouseEvent;
 
-const COMPONENT_NAME: &str = "sfui-button";
+pub(crate) const COMPONENT_NAME: &str = "sfui-button";
 const DEFAULT_CHIPPED_BUTTON_WIDTH: i32 = 100;
 const DEFAULT_CHIPPED_BUTTON_HEIGHT: i32 = 40;
 
@@ -52,6 +56,8 @@ pub struct Button<XMSG> {
     button_width: Option<f32>,
     button_height: Option<f32>,
     children: Vec<Node<XMSG>>,
+    /// namespace of the class names, so the styles of this button don't affect other buttons
+    scope: Scope,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -95,6 +101,12 @@ impl FromStr for Feature {
     }
 }
 
+impl AttributeValues for Feature {
+    const VALUES: &'static [&'static str] = &[
+        "regular", "skewed", "muted", "chipped", "simple", "disabled",
+    ];
+}
+
 impl Into<frame::Feature> for Feature {
     fn into(self) -> frame::Feature {
         frame::Feature {
@@ -133,6 +145,7 @@ where
             button_width: None,
             button_height: None,
             children: vec![],
+            scope: Scope::new(COMPONENT_NAME),
         }
     }
 }
@@ -148,11 +161,25 @@ where
         }
     }
 
+    pub fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
     pub fn set_feature(&mut self, feature: Feature) {
         self.feature = feature;
         self.frame.set_feature(feature.into());
     }
 
+    /// animate the button in and out, see [`Frame::set_assembly`]
+    pub fn set_assembly(&mut self, assembly: Assembly) {
+        self.frame.set_assembly(assembly);
+    }
+
+    /// disable or enable the button, keeping the rest of the features
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.feature.disabled = disabled;
+    }
+
     fn computed_width(&self) -> i32 {
         // use the supplied width if it is specified
         if let Some(width) = self.width {
@@ -202,16 +229,19 @@ where
             [],
             [
                 div(
-                    [class("highlight"), on_transitionend(|_| Msg::HighlightEnd)],
+                    [
+                        class(self.scope.class("highlight")),
+                        on_transitionend(|_| Msg::HighlightEnd),
+                    ],
                     [],
                 ),
                 div(
-                    [class("button_wrap")],
+                    [class(self.scope.class("button_wrap"))],
                     [button(
                         [
-                            class("button"),
+                            class(self.scope.class("button")),
                             if let Some(ref status) = self.status {
-                                class(status.class_name())
+                                class(self.scope.class(status.class_name()))
                             } else {
                                 empty_attr()
                             },
@@ -272,30 +302,33 @@ where
             .join(" ");
 
         div(
-            [class("chipped_wrapper")],
+            [class(self.scope.class("chipped_wrapper"))],
             [
                 svg(
                     [
                         xmlns("http://www.w3.org/2000/svg"),
                         preserve_aspect_ratio("none"),
-                        class("chipped_svg"),
+                        class(self.scope.class("chipped_svg")),
                         view_box([0, 0, width, height]),
                     ],
                     [
                         polygon(
                             [
-                                class("chipped_polygon"),
+                                class(self.scope.class("chipped_polygon")),
                                 points(poly_points_str),
                                 on_transitionend(|_| Msg::HighlightEnd),
                             ],
                             [],
                         ),
-                        polygon([class("triangle"), points(triangle_points)], []),
+                        polygon(
+                            [class(self.scope.class("triangle")), points(triangle_points)],
+                            [],
+                        ),
                     ],
                 ),
                 button(
                     [
-                        class("chipped_button"),
+                        class(self.scope.class("chipped_button")),
                         disabled(self.feature.disabled),
                         style! {width: px(width)},
                         style! {height: px(height)},
@@ -308,8 +341,6 @@ where
     }
 }
 
-// Note: we are not using the custom element macro yet
-// since, there are hiccups at the moment
 impl<XMSG> Container<Msg, XMSG> for Button<XMSG>
 where
     XMSG: 'static,
@@ -371,22 +402,35 @@ where
         div(
             [
                 class(COMPONENT_NAME),
+                class(self.scope.class(COMPONENT_NAME)),
                 classes_flag([
-                    ("clicked", self.clicked),
-                    ("click_highlights", self.feature.click_highlights),
-                    ("expand_corners", self.feature.expand_corners),
-                    ("has_corner_box_shadow", self.feature.has_corner_box_shadow),
-                    ("has_underline", self.feature.has_underline),
-                    ("hovered", self.hovered),
-                    ("skewed", self.feature.skewed),
-                    ("chipped", self.feature.chipped),
+                    (self.scope.class("clicked"), self.clicked),
+                    (
+                        self.scope.class("click_highlights"),
+                        self.feature.click_highlights,
+                    ),
+                    (
+                        self.scope.class("expand_corners"),
+                        self.feature.expand_corners,
+                    ),
+                    (
+                        self.scope.class("has_corner_box_shadow"),
+                        self.feature.has_corner_box_shadow,
+                    ),
+                    (
+                        self.scope.class("has_underline"),
+                        self.feature.has_underline,
+                    ),
+                    (self.scope.class("hovered"), self.hovered),
+                    (self.scope.class("skewed"), self.feature.skewed),
+                    (self.scope.class("chipped"), self.feature.chipped),
                     // setting this will also disable the div, therefore will not activate the
                     // events on it
-                    ("disabled", self.feature.disabled),
-                    ("hidden", self.feature.hidden),
+                    (self.scope.class("disabled"), self.feature.disabled),
+                    (self.scope.class("hidden"), self.feature.hidden),
                 ]),
                 if let Some(ref status) = self.status {
-                    class(status.class_name())
+                    class(self.scope.class(status.class_name()))
                 } else {
                     empty_attr()
                 },
@@ -411,7 +455,7 @@ where
                     .view([
                         view_if(
                             self.feature.has_underline,
-                            div([class("underline underline-bottom")], []),
+                            div([class(self.scope.class("underline underline-bottom"))], []),
                         ),
                         self.view_button(),
                     ])
@@ -437,7 +481,7 @@ where
         let main = jss! {
 
             // the ROOT component style
-           COMPONENT_NAME: {
+           format!(".{}",COMPONENT_NAME): {
                 display: "inline-block",
                 padding: px(1),
                 position: "relative",
@@ -692,7 +736,7 @@ where
 
         };
 
-        [vec![main], self.frame.style()].concat()
+        [vec![self.scope.style(&main)], self.frame.style()].concat()
     }
 }
 
@@ -706,6 +750,11 @@ impl<XMSG> Button<XMSG> {
         self.frame.set_status(status);
     }
 
+    pub fn clear_status(&mut self) {
+        self.status = None;
+        self.frame.clear_status();
+    }
+
     fn calc_button_dimension(&mut self) {
         if let Some(chipped_button) = &self.chipped_button {
             let rect = chipped_button.get_bounding_client_rect();
@@ -822,20 +871,49 @@ impl Feature {
     }
 }
 
-#[custom_element("sfui-button")]
+impl<XMSG> DeclaredAttributes for Button<XMSG>
+where
+    XMSG: 'static,
+{
+    fn attributes() -> Vec<AttributeDef<Self>> {
+        vec![
+            AttributeDef::parsed("label", |app: &mut Self, label: String| {
+                app.label = label;
+            }),
+            AttributeDef::number("width", |app: &mut Self, width: f64| {
+                app.width = Some(width as i32);
+            }),
+            AttributeDef::number("height", |app: &mut Self, height: f64| {
+                app.height = Some(height as i32);
+            }),
+            AttributeDef::theme_primary(|app: &Self| app.theme.clone(), Self::set_theme),
+            AttributeDef::theme_background(|app: &Self| app.theme.clone(), Self::set_theme),
+            AttributeDef::enumerated("feature", Self::set_feature),
+            AttributeDef::clearable_enumerated("status", Self::set_status, Self::clear_status),
+            AttributeDef::flag("disabled", Self::set_disabled),
+            AttributeDef::enumerated("assembly", |app: &mut Self, trigger: AssemblyTrigger| {
+                app.frame.set_assembly_trigger(trigger);
+            }),
+            AttributeDef::number("assembly-duration", |app: &mut Self, duration_ms: usize| {
+                app.frame.set_assembly_duration(duration_ms);
+            }),
+            AttributeDef::number("assembly-stagger", |app: &mut Self, stagger_ms: usize| {
+                app.frame.set_assembly_stagger(stagger_ms);
+            }),
+            AttributeDef::flag("assembled", |app: &mut Self, assembled: bool| {
+                app.frame.set_assembled(assembled);
+            }),
+        ]
+    }
+}
+
 impl<XMSG> WebComponent<Msg> for Button<XMSG>
 where
     XMSG: 'static,
 {
     /// what attributes this component is interested in
     fn observed_attributes() -> Vec<&'static str> {
-        vec![
-            "label",
-            "theme-primary",
-            "theme-background",
-            "feature",
-            "status",
-        ]
+        Self::attribute_names()
     }
 
     /// called when any of the attributes in observed_attributes is changed
@@ -845,62 +923,14 @@ where
         _old_value: Option<String>,
         new_value: Option<String>,
     ) {
-        let mut app = program.app_mut();
-        match attr_name {
-            "label" => {
-                if let Some(label) = new_value {
-                    app.label = label;
-                }
-            }
-            "width" => {
-                if let Some(new_value) = new_value {
-                    if let Ok(v) = str::parse::<f64>(&new_value) {
-                        app.width = Some(v as i32);
-                    }
-                }
-            }
-            "height" => {
-                if let Some(new_value) = new_value {
-                    if let Ok(v) = str::parse::<f64>(&new_value) {
-                        app.height = Some(v as i32);
-                    }
-                }
-            }
-            "theme-primary" => {
-                if let Some(primary) = new_value {
-                    let background = &app.theme.background_color;
-                    let theme =
-                        Theme::from_str(&primary, background).expect("must be a valid theme");
-                    app.set_theme(theme);
-                }
-            }
-            "theme-background" => {
-                if let Some(background) = new_value {
-                    let primary = &app.theme.primary_color;
-                    let theme =
-                        Theme::from_str(primary, &background).expect("must be a valid theme");
-                    app.set_theme(theme);
-                }
-            }
-            "feature" => {
-                if let Some(v) = new_value {
-                    if let Ok(feature) = Feature::from_str(&v) {
-                        app.set_feature(feature);
-                    }
-                }
-            }
-            "status" => {
-                if let Some(v) = new_value {
-                    if let Ok(status) = Status::from_str(&v) {
-                        app.set_status(status);
-                    }
-                }
-            }
-            _ => (),
-        }
+        program.app_mut().apply_attribute(attr_name, new_value);
     }
 
     fn connected_callback(&mut self) {}
     fn disconnected_callback(&mut self) {}
     fn adopted_callback(&mut self) {}
 }
+
+impl CustomElement<Msg> for Button<()> {}
+
+register_custom_element!(COMPONENT_NAME, ButtonCustomElement, Button<()>, Msg);
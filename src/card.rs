@@ -1,28 +1,133 @@
-use crate::frame::{self, Frame};
+use crate::custom_element::{
+    register_custom_element, AttributeDef, CustomElement, DeclaredAttributes,
+};
+use crate::dice::{self, Dice, DiceTrigger};
+use crate::frame::{self, Assembly, AssemblyTrigger, Frame};
+use crate::scope::Scope;
+use crate::Status;
 use crate::Theme;
+use sauron::wasm_bindgen::JsCast;
 use sauron::{
     dom::{Callback, WebComponent},
     html::{attributes::*, events::*, *},
     *,
 };
+use web_sys::MouseEvent;
+
+pub use collection::{CardCollection, Layout};
+pub use media::MediaReveal;
+use media::MediaState;
+
+pub mod collection;
+pub mod icon;
+mod media;
+pub mod product;
+
+pub(crate) const COMPONENT_NAME: &str = "sfui-card";
+/// the keyframes of the shimmering placeholder displayed while the media is loading
+const SKELETON_ANIMATION: &str = "sfui-card-skeleton";
 
 pub enum Msg<XMSG> {
     External(XMSG),
-    FrameMsg(frame::Msg<XMSG>),
+    FrameMsg(Box<frame::Msg<Msg<XMSG>>>),
+    DiceMsg(Box<dice::Msg<Msg<XMSG>>>),
+    MediaLoaded,
+    MediaFailed,
+    Click(MouseEvent),
+    /// the mouse entered or left the card
+    Hover(bool),
+    /// the selected state of the card is toggled by clicking it
+    Select(bool),
+    /// show or hide the expandable content
+    ToggleExpand,
+    /// the elements assigned to the `media` slot changed, whether there are any
+    MediaSlotChanged(bool),
+    /// the elements assigned to the `actions` slot changed, whether there are any
+    ActionsSlotChanged(bool),
 }
 
+/// A card wrapped in a frame, only the parts which are provided are displayed
 pub struct Card<XMSG> {
     theme: Theme,
     children: Vec<Node<XMSG>>,
-    frame: Frame<XMSG>,
+    frame: Frame<Msg<XMSG>>,
+    /// project the light dom children of `sfui-card` using the default, `header`, `media`,
+    /// `actions` and `footer` slots
+    slotted: bool,
+    /// namespace of the class names, so the styles of this card don't affect other cards
+    scope: Scope,
+    heading: Option<String>,
+    subtitle: Option<String>,
+    description: Option<String>,
+    /// the url of the image displayed at the top of the card
+    media: Option<String>,
+    /// the alternate text of the image
+    media_alt: String,
+    /// the ratio of the media box, ie: `16 / 9`, so the card doesn't shift when the image is loaded
+    media_aspect_ratio: Option<String>,
+    media_reveal: MediaReveal,
+    media_state: MediaState,
+    /// slices the image in when the media is revealed with [`MediaReveal::Dice`]
+    media_dice: Dice<Msg<XMSG>>,
+    /// an element is assigned to the `media` slot
+    has_slotted_media: bool,
+    price: Option<String>,
+    /// additional information displayed next to the price, such as the stock or rating
+    meta: Option<String>,
+    /// action buttons displayed at the bottom of the card
+    actions: Vec<Node<XMSG>>,
+    /// an element is assigned to the `actions` slot
+    has_slotted_actions: bool,
+    /// the whole card links to this url
+    href: Option<String>,
+    /// the card has additional content which is revealed when expanded
+    expandable: bool,
+    expanded: bool,
+    /// the content which is only displayed when the card is expanded
+    expandable_content: Vec<Node<XMSG>>,
+    click_listeners: Vec<Callback<MouseEvent, XMSG>>,
+    hover_listeners: Vec<Callback<bool, XMSG>>,
+    select_listeners: Vec<Callback<bool, XMSG>>,
+    expand_listeners: Vec<Callback<bool, XMSG>>,
 }
 
-impl<XMSG> Default for Card<XMSG> {
+impl<XMSG> Default for Card<XMSG>
+where
+    XMSG: 'static,
+{
     fn default() -> Self {
+        let mut frame = Frame::default();
+        frame.add_click_listener(Msg::Click);
+        frame.add_hover_listener(Msg::Hover);
+        frame.add_select_listener(Msg::Select);
         Self {
             theme: Theme::default(),
             children: vec![],
-            frame: Frame::default(),
+            frame,
+            slotted: false,
+            scope: Scope::new(COMPONENT_NAME),
+            heading: None,
+            subtitle: None,
+            description: None,
+            media: None,
+            media_alt: String::new(),
+            media_aspect_ratio: None,
+            media_reveal: MediaReveal::default(),
+            media_state: MediaState::Loading,
+            media_dice: Self::new_media_dice(),
+            has_slotted_media: false,
+            price: None,
+            meta: None,
+            actions: vec![],
+            has_slotted_actions: false,
+            href: None,
+            expandable: false,
+            expanded: false,
+            expandable_content: vec![],
+            click_listeners: vec![],
+            hover_listeners: vec![],
+            select_listeners: vec![],
+            expand_listeners: vec![],
         }
     }
 }
@@ -35,8 +140,442 @@ impl<XMSG> Card<XMSG> {
 
     pub fn set_theme(&mut self, theme: Theme) {
         self.theme = theme.clone();
+        self.media_dice.set_theme(theme.clone());
         self.frame.set_theme(theme);
     }
+
+    pub fn with_heading(mut self, heading: &str) -> Self {
+        self.set_heading(heading);
+        self
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.set_description(description);
+        self
+    }
+
+    pub fn with_media(mut self, url: &str, alt: &str) -> Self {
+        self.set_media(url, alt);
+        self
+    }
+
+    /// animate the card in and out, see [`Frame::set_assembly`]
+    pub fn set_assembly(&mut self, assembly: Assembly) {
+        self.frame.set_assembly(assembly);
+    }
+
+    /// color the frame of the card with the status
+    pub fn set_status(&mut self, status: Status) {
+        self.frame.set_status(status);
+    }
+
+    /// the status is owned by the frame of the card
+    pub fn clear_status(&mut self) {
+        self.frame.clear_status();
+    }
+
+    pub fn set_heading(&mut self, heading: &str) {
+        self.heading = Some(heading.to_string());
+    }
+
+    pub fn clear_heading(&mut self) {
+        self.heading = None;
+    }
+
+    pub fn set_subtitle(&mut self, subtitle: &str) {
+        self.subtitle = Some(subtitle.to_string());
+    }
+
+    pub fn clear_subtitle(&mut self) {
+        self.subtitle = None;
+    }
+
+    pub fn set_description(&mut self, description: &str) {
+        self.description = Some(description.to_string());
+    }
+
+    pub fn clear_description(&mut self) {
+        self.description = None;
+    }
+
+    /// display the image at `url` at the top of the card
+    pub fn set_media(&mut self, url: &str, alt: &str) {
+        self.set_media_url(url);
+        self.set_media_alt(alt);
+    }
+
+    /// change the url of the image, the image is only loaded and revealed again
+    /// when the url changes
+    pub fn set_media_url(&mut self, url: &str) {
+        if self.media.as_deref() != Some(url) {
+            self.media = Some(url.to_string());
+            self.media_state = MediaState::Loading;
+            // the new image is revealed again once it is loaded
+            self.media_dice.hide();
+        }
+    }
+
+    /// the alternate text of the image
+    pub fn set_media_alt(&mut self, alt: &str) {
+        self.media_alt = alt.to_string();
+    }
+
+    pub fn clear_media_alt(&mut self) {
+        self.media_alt.clear();
+    }
+
+    /// reserve the space of the media with this ratio, ie: `16 / 9`, before the image is loaded
+    pub fn set_media_aspect_ratio(&mut self, ratio: &str) {
+        self.media_aspect_ratio = Some(ratio.to_string());
+    }
+
+    pub fn clear_media_aspect_ratio(&mut self) {
+        self.media_aspect_ratio = None;
+    }
+
+    /// how the media image is revealed once it is loaded
+    pub fn set_media_reveal(&mut self, reveal: MediaReveal) {
+        self.media_reveal = reveal;
+    }
+
+    /// the price, formatted as it is displayed, ie: `$10.99`
+    pub fn set_price(&mut self, price: &str) {
+        self.price = Some(price.to_string());
+    }
+
+    pub fn clear_price(&mut self) {
+        self.price = None;
+    }
+
+    pub fn set_meta(&mut self, meta: &str) {
+        self.meta = Some(meta.to_string());
+    }
+
+    pub fn clear_meta(&mut self) {
+        self.meta = None;
+    }
+
+    /// set the action buttons, displayed at the bottom of the card
+    pub fn set_actions(&mut self, actions: impl IntoIterator<Item = Node<XMSG>>) {
+        self.actions = actions.into_iter().collect();
+    }
+
+    /// make the whole card a link to `url`
+    pub fn set_href(&mut self, url: &str) {
+        self.href = Some(url.to_string());
+    }
+
+    pub fn clear_href(&mut self) {
+        self.href = None;
+    }
+
+    /// clicking the card toggles its selected state, see [`Frame::set_selectable`]
+    pub fn set_selectable(&mut self, selectable: bool) {
+        self.frame.set_selectable(selectable);
+    }
+
+    pub fn set_selected(&mut self, selected: bool) {
+        self.frame.set_selected(selected);
+    }
+
+    pub fn is_selected(&self) -> bool {
+        self.frame.is_selected()
+    }
+
+    /// the card has a toggle which reveals additional content
+    pub fn set_expandable(&mut self, expandable: bool) {
+        self.expandable = expandable;
+    }
+
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.expanded = expanded;
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// the content revealed when the card is expanded, this also makes the card expandable
+    pub fn set_expandable_content(&mut self, content: impl IntoIterator<Item = Node<XMSG>>) {
+        self.expandable_content = content.into_iter().collect();
+        self.expandable = true;
+    }
+
+    /// a text part of the card, which is only displayed when it is set
+    fn view_text(&self, name: &str, value: &Option<String>) -> Node<Msg<XMSG>> {
+        if let Some(value) = value {
+            div([class(self.scope.class(name))], [text(value)])
+        } else {
+            node_list([])
+        }
+    }
+
+    /// the actions set on the card followed by the `actions` rendered along with the view
+    fn view_actions(&self, actions: Vec<Node<XMSG>>) -> Node<Msg<XMSG>> {
+        let is_empty = self.actions.is_empty() && actions.is_empty();
+        view_if(
+            !is_empty || self.slotted,
+            div(
+                [
+                    class(self.scope.class("actions")),
+                    // the wrapper of the slot is hidden until an element is assigned to it
+                    classes_flag([(
+                        self.scope.class("empty"),
+                        is_empty && !self.has_slotted_actions,
+                    )]),
+                    on("slotchange", |event| {
+                        Msg::ActionsSlotChanged(has_assigned_nodes(&event))
+                    }),
+                ],
+                self.actions
+                    .iter()
+                    .cloned()
+                    .chain(actions)
+                    .map(|node| node.map_msg(Msg::External))
+                    .chain(self.slotted.then(|| node! { <slot name="actions"></slot> })),
+            ),
+        )
+    }
+
+    /// the expandable content is always rendered, and is revealed by transitioning
+    /// the height of its grid row from `0fr` to `1fr`
+    fn view_expandable(&self) -> Node<Msg<XMSG>> {
+        view_if(
+            self.expandable,
+            node_list([
+                div(
+                    [class(self.scope.class("expandable"))],
+                    [div(
+                        [class(self.scope.class("expandable_inner"))],
+                        self.expandable_content
+                            .iter()
+                            .cloned()
+                            .map(|node| node.map_msg(Msg::External))
+                            .chain(
+                                self.slotted
+                                    .then(|| node! { <slot name="expandable"></slot> }),
+                            ),
+                    )],
+                ),
+                button(
+                    [
+                        class(self.scope.class("expand_toggle")),
+                        attr("aria-expanded", self.expanded.to_string()),
+                        on_click(|me| {
+                            // don't let the frame treat it as a click on the card
+                            me.stop_propagation();
+                            Msg::ToggleExpand
+                        }),
+                    ],
+                    [text(if self.expanded {
+                        "Show less"
+                    } else {
+                        "Show more"
+                    })],
+                ),
+            ]),
+        )
+    }
+}
+
+impl<XMSG> Card<XMSG>
+where
+    XMSG: 'static,
+{
+    /// remove the image along with its reveal animation
+    pub fn clear_media(&mut self) {
+        self.media = None;
+        self.media_state = MediaState::Loading;
+        let mut media_dice = Self::new_media_dice();
+        media_dice.set_theme(self.theme.clone());
+        self.media_dice = media_dice;
+    }
+
+    fn new_media_dice() -> Dice<Msg<XMSG>> {
+        // the reveal is not started by a user gesture, so it is silent
+        Dice::new()
+            .with_trigger(DiceTrigger::Manual)
+            .with_sound(false)
+    }
+
+    /// render the card with additional `actions`, for the components which wrap a card
+    /// and render their own buttons in its actions
+    pub fn view_with_actions(
+        &self,
+        content: impl IntoIterator<Item = Node<XMSG>>,
+        actions: impl IntoIterator<Item = Node<XMSG>>,
+    ) -> Node<Msg<XMSG>> {
+        let body = content
+            .into_iter()
+            .chain(self.children.clone().into_iter())
+            .map(|node| node.map_msg(Msg::External))
+            .collect::<Vec<_>>();
+        let has_price_row = self.price.is_some() || self.meta.is_some();
+        div(
+            [
+                class(COMPONENT_NAME),
+                class(self.scope.class("card")),
+                classes_flag([(self.scope.class("expanded"), self.expanded)]),
+            ],
+            [self
+                .frame
+                .view([
+                    self.view_link(),
+                    view_if(self.slotted, node! { <slot name="header"></slot> }),
+                    self.view_media(),
+                    node! {
+                         <div class={self.scope.class("card-body")}>
+                             { self.view_text("heading", &self.heading) }
+                             { self.view_text("subtitle", &self.subtitle) }
+                             { self.view_text("description", &self.description) }
+                             {
+                                 view_if(has_price_row, node! {
+                                     <div class={self.scope.class("price_row")}>
+                                         { self.view_text("price", &self.price) }
+                                         { self.view_text("meta", &self.meta) }
+                                     </div>
+                                 })
+                             }
+                             { for child in body { child } }
+                             { view_if(self.slotted, node! { <slot></slot> }) }
+                         </div>
+                    },
+                    self.view_expandable(),
+                    self.view_actions(actions.into_iter().collect()),
+                    view_if(self.slotted, node! { <slot name="footer"></slot> }),
+                ])
+                .map_msg(|fmsg| Msg::FrameMsg(Box::new(fmsg)))],
+        )
+    }
+
+    fn view_media(&self) -> Node<Msg<XMSG>> {
+        let has_image = self.media.is_some();
+        view_if(
+            has_image || self.slotted,
+            div(
+                [
+                    class(self.scope.class("media")),
+                    classes_flag([
+                        (
+                            self.scope.class("loading"),
+                            has_image && self.media_state == MediaState::Loading,
+                        ),
+                        (
+                            self.scope.class("failed"),
+                            has_image && self.media_state == MediaState::Failed,
+                        ),
+                        (
+                            self.scope.class("fade"),
+                            self.media_reveal == MediaReveal::Fade,
+                        ),
+                        (
+                            self.scope.class("empty"),
+                            !has_image && !self.has_slotted_media,
+                        ),
+                    ]),
+                    on("slotchange", |event| {
+                        Msg::MediaSlotChanged(has_assigned_nodes(&event))
+                    }),
+                    if let Some(ratio) = &self.media_aspect_ratio {
+                        style! { aspect_ratio: ratio.clone() }
+                    } else {
+                        empty_attr()
+                    },
+                ],
+                self.media
+                    .iter()
+                    .map(|url| self.view_media_image(url))
+                    .chain(
+                        (has_image && self.media_state == MediaState::Failed).then(|| {
+                            div(
+                                [class(self.scope.class("media_fallback"))],
+                                [text(if self.media_alt.is_empty() {
+                                    "Image unavailable"
+                                } else {
+                                    &self.media_alt
+                                })],
+                            )
+                        }),
+                    )
+                    .chain(self.slotted.then(|| node! { <slot name="media"></slot> })),
+            ),
+        )
+    }
+
+    /// the link stretched over the whole card, the buttons of the card are placed above it
+    /// so they can be clicked without following the link
+    fn view_link(&self) -> Node<Msg<XMSG>> {
+        if let Some(url) = &self.href {
+            a(
+                [
+                    class(self.scope.class("card_link")),
+                    href(url),
+                    attr("aria-label", self.heading.as_deref().unwrap_or(url)),
+                ],
+                [],
+            )
+        } else {
+            node_list([])
+        }
+    }
+
+    fn view_media_image(&self, url: &str) -> Node<Msg<XMSG>> {
+        let image = img(
+            [
+                class(self.scope.class("media_image")),
+                src(url),
+                alt(&self.media_alt),
+                attr("loading", "lazy"),
+                attr("decoding", "async"),
+                on("load", |_| Msg::MediaLoaded),
+                on("error", |_| Msg::MediaFailed),
+            ],
+            [],
+        );
+        // the dice keeps the image hidden until it is revealed
+        if self.media_reveal == MediaReveal::Dice {
+            self.media_dice
+                .view([image])
+                .map_msg(|dmsg| Msg::DiceMsg(Box::new(dmsg)))
+        } else {
+            image
+        }
+    }
+
+    pub fn add_click_listener<F>(&mut self, f: F)
+    where
+        F: Fn(MouseEvent) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.click_listeners.push(cb);
+    }
+
+    /// listen to the mouse entering and leaving the card
+    pub fn add_hover_listener<F>(&mut self, f: F)
+    where
+        F: Fn(bool) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.hover_listeners.push(cb);
+    }
+
+    /// listen to the selected state, each time it is toggled by clicking the card
+    pub fn add_select_listener<F>(&mut self, f: F)
+    where
+        F: Fn(bool) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.select_listeners.push(cb);
+    }
+
+    /// listen to the expanded state, each time it is toggled
+    pub fn add_expand_listener<F>(&mut self, f: F)
+    where
+        F: Fn(bool) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.expand_listeners.push(cb);
+    }
 }
 
 impl<XMSG> Container<Msg<XMSG>, XMSG> for Card<XMSG>
@@ -44,30 +583,81 @@ where
     XMSG: 'static,
 {
     fn update(&mut self, msg: Msg<XMSG>) -> Effects<Msg<XMSG>, XMSG> {
-        Effects::none()
+        match msg {
+            Msg::External(xmsg) => Effects::with_external([xmsg]),
+            // the frame needs its messages for measuring and animating itself
+            Msg::FrameMsg(fmsg) => {
+                let effects =
+                    <Frame<Msg<XMSG>> as Container<frame::Msg<Msg<XMSG>>, Msg<XMSG>>>::update(
+                        &mut self.frame,
+                        *fmsg,
+                    );
+                effects.localize(|fmsg| Msg::FrameMsg(Box::new(fmsg)))
+            }
+            Msg::DiceMsg(dmsg) => {
+                let effects =
+                    <Dice<Msg<XMSG>> as Container<dice::Msg<Msg<XMSG>>, Msg<XMSG>>>::update(
+                        &mut self.media_dice,
+                        *dmsg,
+                    );
+                effects.localize(|dmsg| Msg::DiceMsg(Box::new(dmsg)))
+            }
+            Msg::MediaLoaded => {
+                // the slices of the dice are copies of the image, which are loaded too
+                if self.media_state == MediaState::Loading {
+                    self.media_state = MediaState::Loaded;
+                    if self.media_reveal == MediaReveal::Dice {
+                        return Effects::with_local([Msg::DiceMsg(Box::new(dice::Msg::AnimateIn))]);
+                    }
+                }
+                Effects::none()
+            }
+            Msg::MediaFailed => {
+                self.media_state = MediaState::Failed;
+                Effects::none()
+            }
+            Msg::Click(mouse_event) => {
+                let external = self
+                    .click_listeners
+                    .iter()
+                    .map(|listener| listener.emit(mouse_event.clone()));
+                Effects::with_external(external)
+            }
+            Msg::Hover(hovered) => {
+                let external = self
+                    .hover_listeners
+                    .iter()
+                    .map(|listener| listener.emit(hovered));
+                Effects::with_external(external)
+            }
+            Msg::Select(selected) => {
+                let external = self
+                    .select_listeners
+                    .iter()
+                    .map(|listener| listener.emit(selected));
+                Effects::with_external(external)
+            }
+            Msg::ToggleExpand => {
+                self.expanded = !self.expanded;
+                let external = self
+                    .expand_listeners
+                    .iter()
+                    .map(|listener| listener.emit(self.expanded));
+                Effects::with_external(external)
+            }
+            Msg::MediaSlotChanged(has_slotted_media) => {
+                self.has_slotted_media = has_slotted_media;
+                Effects::none()
+            }
+            Msg::ActionsSlotChanged(has_slotted_actions) => {
+                self.has_slotted_actions = has_slotted_actions;
+                Effects::none()
+            }
+        }
     }
 
     fn view(&self, content: impl IntoIterator<Item = Node<XMSG>>) -> Node<Msg<XMSG>> {
-        node! {
-             <div class="card">
-             {
-                 self.frame.view([
-                        node! {
-                             <div class="card-body">
-                                 <a href="#" class="product">
-                                     <div class="img" style="background-image: url(assets/img/pos/product-1.jpg)"></div>
-                                     <div class="info">
-                                         <div class="title">"Grill Chicken Chop®"</div>
-                                         <div class="desc">chicken, egg, mushroom, salad</div>
-                                         <div class="price">$10.99</div>
-                                     </div>
-                                 </a>
-                             </div>
-                        }
-                ]).map_msg(|fmsg|Msg::FrameMsg(fmsg))
-             }
-             </div>
-        }
+        self.view_with_actions(content, [])
     }
 
     fn append_child(&mut self, child: Node<XMSG>) {
@@ -75,45 +665,285 @@ where
     }
 
     fn style(&self) -> Vec<String> {
-        self.frame.style()
+        let theme = &self.theme;
+        let base = &theme.controls;
+
+        let main = jss! {
+            ".card": {
+                display: "inline-block",
+            },
+
+            ".media": {
+                position: "relative",
+                overflow: "hidden",
+            },
+
+            ".media_image": {
+                display: "block",
+                width: percent(100),
+                height: percent(100),
+                object_fit: "cover",
+            },
+
+            ".loading": {
+                min_height: px(120),
+                background: format!(
+                    "linear-gradient(90deg, {} 25%, {} 50%, {} 75%)",
+                    base.content_background_color, theme.accent_shadow, base.content_background_color
+                ),
+                background_size: "200% 100%",
+                animation: format!("{} 1.2s linear infinite", SKELETON_ANIMATION),
+            },
+
+            ".loading .media_image": {
+                opacity: 0,
+            },
+
+            ".fade .media_image": {
+                transition: "opacity 300ms ease-out",
+            },
+
+            ".failed .media_image": {
+                display: "none",
+            },
+
+            ".media_fallback": {
+                display: "flex",
+                align_items: "center",
+                justify_content: "center",
+                min_height: px(120),
+                height: percent(100),
+                box_sizing: "border-box",
+                padding: px(10),
+                border: format!("1px dashed {}", base.border_color),
+                color: theme.secondary_color.clone(),
+                font_size: "0.85em",
+            },
+
+            ".card-body": {
+                display: "flex",
+                flex_direction: "column",
+                gap: px(4),
+            },
+
+            ".heading": {
+                font_size: "1.25em",
+                font_weight: "bold",
+                color: theme.primary_color.clone(),
+            },
+
+            ".subtitle": {
+                color: theme.secondary_color.clone(),
+            },
+
+            ".description": {
+                color: base.button_text_color.clone(),
+            },
+
+            ".price_row": {
+                display: "flex",
+                justify_content: "space-between",
+                align_items: "baseline",
+                gap: px(10),
+            },
+
+            ".price": {
+                font_weight: "bold",
+                color: theme.accent_color.clone(),
+            },
+
+            ".meta": {
+                font_size: "0.85em",
+                color: theme.secondary_color.clone(),
+            },
+
+            ".actions": {
+                display: "flex",
+                gap: px(8),
+                margin_top: px(8),
+            },
+
+            ".media.empty, .actions.empty": {
+                display: "none",
+            },
+
+            ".card_link": {
+                position: "absolute",
+                top: 0,
+                left: 0,
+                width: percent(100),
+                height: percent(100),
+                z_index: 1,
+            },
+
+            ".actions, .expand_toggle": {
+                position: "relative",
+                z_index: 2,
+            },
+
+            ".expandable": {
+                display: "grid",
+                grid_template_rows: "0fr",
+                transition: format!("grid-template-rows {}ms ease-in-out", 250),
+            },
+
+            ".expanded .expandable": {
+                grid_template_rows: "1fr",
+            },
+
+            ".expandable_inner": {
+                overflow: "hidden",
+                min_height: 0,
+            },
+
+            ".expand_toggle": {
+                background: "none",
+                border: "none",
+                padding: 0,
+                margin_top: px(4),
+                cursor: "pointer",
+                color: theme.controls.link_color.clone(),
+            },
+        };
+
+        let skeleton = format!(
+            "@keyframes {} {{ from {{ background-position: 100% 0; }} to {{ background-position: -100% 0; }} }}",
+            SKELETON_ANIMATION
+        );
+
+        // the dice and its content container wraps the image when it is revealed with slices,
+        // the dice class is not scoped
+        let media_dice = format!(
+            ".{media} .{dice}, .{media} .{dice} > div:last-child {{ display: block; width: 100%; height: 100%; }}",
+            media = self.scope.class("media"),
+            dice = dice::COMPONENT_NAME,
+        );
+
+        [
+            vec![self.scope.style(&main), skeleton, media_dice],
+            self.frame.style(),
+            <Dice<Msg<XMSG>> as Container<dice::Msg<Msg<XMSG>>, Msg<XMSG>>>::style(
+                &self.media_dice,
+            ),
+        ]
+        .concat()
     }
 }
 
-#[custom_element("sfui-card")]
-impl WebComponent<Msg<()>> for Card<()> {
+impl<XMSG> DeclaredAttributes for Card<XMSG>
+where
+    XMSG: 'static,
+{
+    fn attributes() -> Vec<AttributeDef<Self>> {
+        vec![
+            AttributeDef::theme_primary(|app: &Self| app.theme.clone(), Self::set_theme),
+            AttributeDef::theme_background(|app: &Self| app.theme.clone(), Self::set_theme),
+            // not `title`, which is the native tooltip of the element
+            AttributeDef::clearable(
+                "heading",
+                |app: &mut Self, heading: String| app.set_heading(&heading),
+                Self::clear_heading,
+            ),
+            AttributeDef::clearable(
+                "subtitle",
+                |app: &mut Self, subtitle: String| app.set_subtitle(&subtitle),
+                Self::clear_subtitle,
+            ),
+            AttributeDef::clearable(
+                "description",
+                |app: &mut Self, description: String| app.set_description(&description),
+                Self::clear_description,
+            ),
+            AttributeDef::clearable(
+                "media",
+                |app: &mut Self, url: String| app.set_media_url(&url),
+                Self::clear_media,
+            ),
+            AttributeDef::clearable(
+                "media-alt",
+                |app: &mut Self, alt: String| app.set_media_alt(&alt),
+                Self::clear_media_alt,
+            ),
+            AttributeDef::clearable(
+                "media-aspect-ratio",
+                |app: &mut Self, ratio: String| app.set_media_aspect_ratio(&ratio),
+                Self::clear_media_aspect_ratio,
+            ),
+            AttributeDef::enumerated("media-reveal", Self::set_media_reveal),
+            AttributeDef::clearable(
+                "price",
+                |app: &mut Self, price: String| app.set_price(&price),
+                Self::clear_price,
+            ),
+            AttributeDef::clearable(
+                "meta",
+                |app: &mut Self, meta: String| app.set_meta(&meta),
+                Self::clear_meta,
+            ),
+            AttributeDef::clearable(
+                "href",
+                |app: &mut Self, url: String| app.set_href(&url),
+                Self::clear_href,
+            ),
+            AttributeDef::flag("selectable", Self::set_selectable),
+            AttributeDef::flag("selected", Self::set_selected),
+            AttributeDef::flag("expandable", Self::set_expandable),
+            AttributeDef::flag("expanded", Self::set_expanded),
+            AttributeDef::enumerated("assembly", |app: &mut Self, trigger: AssemblyTrigger| {
+                app.frame.set_assembly_trigger(trigger);
+            }),
+            AttributeDef::number("assembly-duration", |app: &mut Self, duration_ms: usize| {
+                app.frame.set_assembly_duration(duration_ms);
+            }),
+            AttributeDef::number("assembly-stagger", |app: &mut Self, stagger_ms: usize| {
+                app.frame.set_assembly_stagger(stagger_ms);
+            }),
+            AttributeDef::flag("assembled", |app: &mut Self, assembled: bool| {
+                app.frame.set_assembled(assembled);
+            }),
+        ]
+    }
+}
+
+/// whether any element is assigned to the slot which dispatched the `slotchange` event
+fn has_assigned_nodes(event: &web_sys::Event) -> bool {
+    event
+        .target()
+        .map(|target| {
+            let slot: web_sys::HtmlSlotElement = target.unchecked_into();
+            slot.assigned_nodes().length() > 0
+        })
+        .unwrap_or(false)
+}
+
+impl<XMSG> WebComponent<Msg<XMSG>> for Card<XMSG>
+where
+    XMSG: 'static,
+{
+    /// what attributes this component is interested in
     fn observed_attributes() -> Vec<&'static str> {
-        vec!["theme-primary", "theme-background"]
+        Self::attribute_names()
     }
 
+    /// called when any of the attributes in observed_attributes is changed
     fn attribute_changed(
-        program: Program<Self, Msg<()>>,
+        program: Program<Self, Msg<XMSG>>,
         attr_name: &str,
         _old_value: Option<String>,
         new_value: Option<String>,
     ) {
-        let mut app = program.app_mut();
-        match attr_name {
-            "theme-primary" => {
-                if let Some(primary) = new_value {
-                    let background = &app.theme.background_color;
-                    let theme =
-                        Theme::from_str(&primary, background).expect("must be a valid theme");
-                    app.set_theme(theme);
-                }
-            }
-            "theme-background" => {
-                if let Some(background) = new_value {
-                    let primary = &app.theme.primary_color;
-                    let theme =
-                        Theme::from_str(primary, &background).expect("must be a valid theme");
-                    app.set_theme(theme);
-                }
-            }
-            _ => (),
-        }
+        program.app_mut().apply_attribute(attr_name, new_value);
     }
 
     fn connected_callback(&mut self) {}
     fn disconnected_callback(&mut self) {}
     fn adopted_callback(&mut self) {}
 }
+
+impl CustomElement<Msg<()>> for Card<()> {
+    fn use_slots(&mut self) {
+        self.slotted = true;
+    }
+}
+
+register_custom_element!(COMPONENT_NAME, CardCustomElement, Card<()>, Msg<()>);
@@ -0,0 +1,559 @@
+use super::Card;
+use crate::card;
+use crate::observer::{SizeObserver, RESIZE_EVENT};
+use crate::scope::Scope;
+use crate::Theme;
+use sauron::wasm_bindgen::JsCast;
+use sauron::{
+    html::{attributes::*, events::*, *},
+    *,
+};
+use std::ops::Range;
+use web_sys::KeyboardEvent;
+
+const COMPONENT_NAME: &str = "sfui-card-collection";
+/// the keyframes of the entry animation of the cards, shared by all the collections
+const ENTER_ANIMATION: &str = "sfui-card-collection-enter";
+/// the number of rows rendered above and below the visible rows when virtualized
+const OVERSCAN_ROWS: usize = 1;
+/// the longest delay of the entry animation, so the cards far down a long list
+/// are not left hidden for long
+const MAX_STAGGER_DELAY_MS: usize = 600;
+
+pub enum Msg<XMSG> {
+    /// a message of the card at this index
+    CardMsg(usize, card::Msg<XMSG>),
+    Mounted(MountEvent),
+    Resized,
+    Scrolled,
+    KeyDown(KeyboardEvent),
+    /// the card at this index received the focus
+    Focused(usize),
+    /// the cell of the card at this index is rendered
+    CellMounted(usize, MountEvent),
+    External(XMSG),
+}
+
+/// How the cards are arranged
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Layout {
+    /// rows of cards with the same height
+    Grid,
+    /// columns of cards, each card keeps its own height
+    Masonry,
+}
+
+/// A responsive grid or masonry of cards, which can be navigated with the arrow keys.
+///
+/// Large lists can be virtualized in the grid layout, only the rows near the visible area
+/// of the collection are rendered. This requires a fixed row height and the height
+/// of the scrollable collection.
+pub struct CardCollection<XMSG> {
+    cards: Vec<Card<XMSG>>,
+    theme: Theme,
+    layout: Layout,
+    /// the minimum width of each column, the number of columns adapts to the available width
+    column_width: f32,
+    gap: f32,
+    /// delay between the entry animation of consecutive cards, the cards are not animated if 0
+    stagger_ms: usize,
+    /// the height of each row, when virtualized
+    row_height: Option<f32>,
+    /// the height of the scrollable collection, when virtualized
+    height: Option<f32>,
+    /// the index of the card which is focused with the keyboard
+    focused: usize,
+    /// the card to focus once its cell is rendered, after scrolling to it
+    pending_focus: Option<usize>,
+    root: Option<web_sys::Element>,
+    size_observer: Option<SizeObserver>,
+    /// the measured width of the collection
+    width: Option<f32>,
+    scroll_top: f32,
+    scope: Scope,
+}
+
+impl<XMSG> Default for CardCollection<XMSG> {
+    fn default() -> Self {
+        Self {
+            cards: vec![],
+            theme: Theme::default(),
+            layout: Layout::Grid,
+            column_width: 240.0,
+            gap: 10.0,
+            stagger_ms: 50,
+            row_height: None,
+            height: None,
+            focused: 0,
+            pending_focus: None,
+            root: None,
+            size_observer: None,
+            width: None,
+            scroll_top: 0.0,
+            scope: Scope::new(COMPONENT_NAME),
+        }
+    }
+}
+
+impl<XMSG> CardCollection<XMSG> {
+    pub fn new(cards: impl IntoIterator<Item = Card<XMSG>>) -> Self {
+        Self {
+            cards: cards.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.set_theme(theme);
+        self
+    }
+
+    /// set the theme of the collection and all of its cards
+    pub fn set_theme(&mut self, theme: Theme) {
+        for card in self.cards.iter_mut() {
+            card.set_theme(theme.clone());
+        }
+        self.theme = theme;
+    }
+
+    pub fn set_layout(&mut self, layout: Layout) {
+        self.layout = layout;
+    }
+
+    /// the minimum width of each column
+    pub fn set_column_width(&mut self, column_width: f32) {
+        self.column_width = column_width;
+    }
+
+    pub fn set_gap(&mut self, gap: f32) {
+        self.gap = gap;
+    }
+
+    /// delay between the entry animation of consecutive cards, set to 0 to disable the animation
+    pub fn set_stagger(&mut self, stagger_ms: usize) {
+        self.stagger_ms = stagger_ms;
+    }
+
+    /// only render the rows near the visible area of the collection, which is `height` tall
+    /// and scrollable. Each row of cards is `row_height` tall.
+    /// This only applies to the grid layout, since the masonry cards have different heights.
+    pub fn set_virtualized(&mut self, row_height: f32, height: f32) {
+        self.row_height = Some(row_height);
+        self.height = Some(height);
+    }
+
+    pub fn push(&mut self, mut card: Card<XMSG>) {
+        card.set_theme(self.theme.clone());
+        self.cards.push(card);
+    }
+
+    pub fn cards(&self) -> &[Card<XMSG>] {
+        &self.cards
+    }
+
+    pub fn cards_mut(&mut self) -> &mut [Card<XMSG>] {
+        &mut self.cards
+    }
+
+    /// the number of columns that fits the measured width of the collection
+    fn columns(&self) -> usize {
+        let column_stride = self.column_width + self.gap;
+        match self.width {
+            Some(width) if column_stride > 0.0 => {
+                (((width + self.gap) / column_stride).floor() as usize).max(1)
+            }
+            _ => 1,
+        }
+    }
+
+    /// the rows are only virtualized when they have a height, otherwise all of them
+    /// would fit in the visible area
+    fn is_virtualized(&self) -> bool {
+        self.layout == Layout::Grid
+            && self.row_height.is_some_and(|row_height| row_height > 0.0)
+            && self.height.is_some()
+    }
+
+    fn row_stride(&self) -> f32 {
+        self.row_height.unwrap_or(0.0) + self.gap
+    }
+
+    /// the index of the cards which are rendered,
+    /// the last row is still rendered when scrolled past the end of the list
+    fn visible_range(&self) -> Range<usize> {
+        let len = self.cards.len();
+        match (self.is_virtualized(), self.height) {
+            (true, Some(height)) => {
+                let columns = self.columns();
+                let stride = self.row_stride();
+                let rows = (len + columns - 1) / columns;
+                let first_row = ((self.scroll_top / stride).floor() as usize)
+                    .saturating_sub(OVERSCAN_ROWS)
+                    .min(rows.saturating_sub(1));
+                let last_row = (((self.scroll_top + height) / stride).ceil() as usize)
+                    .saturating_add(OVERSCAN_ROWS);
+                (first_row * columns).min(len)..last_row.saturating_mul(columns).min(len)
+            }
+            _ => 0..len,
+        }
+    }
+
+    /// the scroll offset which brings the row of the card at `index` into view,
+    /// if it is not visible
+    fn scroll_top_to_reveal(&self, index: usize) -> Option<f32> {
+        if !self.is_virtualized() {
+            return None;
+        }
+        let row = index / self.columns();
+        let top = row as f32 * self.row_stride();
+        let height = self.height.unwrap_or(0.0);
+        (top < self.scroll_top || top + self.row_stride() > self.scroll_top + height).then_some(top)
+    }
+
+    /// move the focus to the card at `index`
+    fn focus_card(&mut self, index: usize) {
+        self.focused = index;
+        let Some(root) = &self.root else {
+            return;
+        };
+        // scroll the row of the card into view, so it is rendered
+        if let Some(top) = self.scroll_top_to_reveal(index) {
+            root.set_scroll_top(top as i32);
+            self.scroll_top = top;
+        }
+        // cards outside of the overscan rows are not rendered yet and are focused
+        // once their cell is mounted
+        let selector = format!("[data-index=\"{}\"]", index);
+        if let Ok(Some(cell)) = root.query_selector(&selector) {
+            self.pending_focus = None;
+            focus_element(&cell);
+        } else {
+            self.pending_focus = Some(index);
+        }
+    }
+
+    /// whether the event is dispatched on one of the cells, not on the content of the cards
+    fn is_cell_event(&self, event: &web_sys::Event) -> bool {
+        let selector = format!(".{}", self.scope.class("cell"));
+        event
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .map(|element| element.matches(&selector).unwrap_or(false))
+            .unwrap_or(false)
+    }
+}
+
+fn focus_element(element: &web_sys::Element) {
+    element
+        .unchecked_ref::<web_sys::HtmlElement>()
+        .focus()
+        .expect("must focus");
+}
+
+impl<XMSG> Container<Msg<XMSG>, XMSG> for CardCollection<XMSG>
+where
+    XMSG: 'static,
+{
+    fn update(&mut self, msg: Msg<XMSG>) -> Effects<Msg<XMSG>, XMSG> {
+        match msg {
+            Msg::CardMsg(index, cmsg) => {
+                if let Some(card) = self.cards.get_mut(index) {
+                    let effects =
+                        <Card<XMSG> as Container<card::Msg<XMSG>, XMSG>>::update(card, cmsg);
+                    effects.map_msg(move |cmsg| Msg::CardMsg(index, cmsg))
+                } else {
+                    Effects::none()
+                }
+            }
+            Msg::Mounted(me) => {
+                let root: web_sys::Element = me.target_node.unchecked_into();
+                self.size_observer = Some(SizeObserver::observe(&root));
+                self.width = Some(root.client_width() as f32);
+                self.root = Some(root);
+                Effects::none()
+            }
+            Msg::Resized => {
+                if let Some(root) = &self.root {
+                    self.width = Some(root.client_width() as f32);
+                }
+                Effects::none()
+            }
+            Msg::Scrolled => {
+                if let Some(root) = &self.root {
+                    self.scroll_top = root.scroll_top() as f32;
+                }
+                Effects::none()
+            }
+            Msg::KeyDown(key_event) => {
+                let len = self.cards.len();
+                // the keys pressed in the content of the cards, such as in inputs, are left alone
+                if len == 0 || !self.is_cell_event(&key_event) {
+                    return Effects::none();
+                }
+                // the masonry cards flow from top to bottom, then to the next column
+                let row_step = match self.layout {
+                    Layout::Grid => self.columns(),
+                    Layout::Masonry => 1,
+                };
+                let current = self.focused.min(len - 1);
+                let next = match key_event.key().as_str() {
+                    "ArrowRight" => Some((current + 1).min(len - 1)),
+                    "ArrowLeft" => Some(current.saturating_sub(1)),
+                    "ArrowDown" => Some((current + row_step).min(len - 1)),
+                    "ArrowUp" => Some(current.saturating_sub(row_step)),
+                    "Home" => Some(0),
+                    "End" => Some(len - 1),
+                    _ => None,
+                };
+                if let Some(next) = next {
+                    key_event.prevent_default();
+                    self.focus_card(next);
+                }
+                Effects::none()
+            }
+            Msg::Focused(index) => {
+                self.focused = index;
+                Effects::none()
+            }
+            Msg::CellMounted(index, me) => {
+                if self.pending_focus == Some(index) {
+                    self.pending_focus = None;
+                    focus_element(me.target_node.unchecked_ref());
+                }
+                Effects::none()
+            }
+            Msg::External(xmsg) => Effects::with_external([xmsg]),
+        }
+    }
+
+    fn view(&self, content: impl IntoIterator<Item = Node<XMSG>>) -> Node<Msg<XMSG>> {
+        let range = self.visible_range();
+        let first = range.start;
+        let virtualized = self.is_virtualized();
+        let columns = self.columns();
+        let rows = (self.cards.len() + columns - 1) / columns;
+        let stride = self.row_stride();
+
+        let cells = self.cards[range.clone()]
+            .iter()
+            .zip(range)
+            .map(|(card, index)| {
+                div(
+                    [
+                        key(index),
+                        class(self.scope.class("cell")),
+                        attr("data-index", index),
+                        attr("role", "listitem"),
+                        // only the focused card is in the tab order, the arrow keys
+                        // moves between the cards
+                        tabindex(if index == self.focused { 0 } else { -1 }),
+                        on_focus(move |_| Msg::Focused(index)),
+                        on_mount(move |me| Msg::CellMounted(index, me)),
+                        style! {
+                            animation_delay: format!(
+                                "{}ms",
+                                ((index - first) * self.stagger_ms).min(MAX_STAGGER_DELAY_MS)
+                            ),
+                        },
+                    ],
+                    [card.view([]).map_msg(move |cmsg| Msg::CardMsg(index, cmsg))],
+                )
+            });
+
+        div(
+            [
+                class(COMPONENT_NAME),
+                class(self.scope.class("collection")),
+                classes_flag([
+                    (self.scope.class("masonry"), self.layout == Layout::Masonry),
+                    (self.scope.class("virtualized"), virtualized),
+                    (self.scope.class("animated"), self.stagger_ms > 0),
+                ]),
+                attr("role", "list"),
+                on_mount(Msg::Mounted),
+                on(RESIZE_EVENT, |_| Msg::Resized),
+                on("scroll", |_| Msg::Scrolled),
+                on_keydown(Msg::KeyDown),
+            ],
+            [
+                div(
+                    [
+                        class(self.scope.class("items")),
+                        if virtualized {
+                            style! {
+                                // the rows above the rendered cards are replaced with padding,
+                                // and the height keeps the scrollbar of the whole list
+                                padding_top: px((first / columns) as f32 * stride),
+                                height: px(rows as f32 * stride),
+                                grid_template_columns: format!("repeat({}, minmax(0, 1fr))", columns),
+                            }
+                        } else {
+                            empty_attr()
+                        },
+                    ],
+                    cells,
+                ),
+                node_list(content.into_iter().map(|node| node.map_msg(Msg::External))),
+            ],
+        )
+    }
+
+    fn style(&self) -> Vec<String> {
+        let theme = &self.theme;
+        let gap = self.gap;
+
+        let main = jss! {
+            ".collection": {
+                display: "block",
+                position: "relative",
+            },
+
+            ".items": {
+                display: "grid",
+                grid_template_columns: format!("repeat(auto-fill, minmax({}, 1fr))", px(self.column_width)),
+                gap: px(gap),
+                box_sizing: "border-box",
+            },
+
+            ".cell": {
+                min_width: 0,
+                outline: "none",
+            },
+
+            ".cell:focus-visible": {
+                outline: format!("1px solid {}", theme.accent_color),
+                outline_offset: px(2),
+            },
+
+            ".masonry .items": {
+                display: "block",
+                column_width: px(self.column_width),
+                column_gap: px(gap),
+            },
+
+            ".masonry .cell": {
+                break_inside: "avoid",
+                margin_bottom: px(gap),
+            },
+
+            ".virtualized": {
+                height: px(self.height.unwrap_or(0.0)),
+                overflow_y: "auto",
+            },
+
+            ".virtualized .items": {
+                grid_auto_rows: px(self.row_height.unwrap_or(0.0)),
+            },
+
+            ".virtualized .cell": {
+                overflow: "hidden",
+            },
+
+            ".animated .cell": {
+                animation: format!("{} 300ms ease-out both", ENTER_ANIMATION),
+            },
+        };
+
+        let enter = format!(
+            "@keyframes {} {{ from {{ opacity: 0; transform: translateY(8px); }} to {{ opacity: 1; transform: none; }} }}",
+            ENTER_ANIMATION
+        );
+
+        // each card has its own scope, only the rendered cards need their styles
+        let card_styles = self.cards[self.visible_range()]
+            .iter()
+            .flat_map(|card| <Card<XMSG> as Container<card::Msg<XMSG>, XMSG>>::style(card));
+
+        [self.scope.style(&main), enter]
+            .into_iter()
+            .chain(card_styles)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collection(len: usize) -> CardCollection<()> {
+        CardCollection::new((0..len).map(|_| Card::default()))
+    }
+
+    /// a virtualized collection 300px tall, with 100px rows and a 10px gap
+    fn virtualized(len: usize, width: f32) -> CardCollection<()> {
+        let mut collection = collection(len);
+        collection.set_column_width(100.0);
+        collection.set_virtualized(100.0, 300.0);
+        collection.width = Some(width);
+        collection
+    }
+
+    #[test]
+    fn columns_fit_the_width() {
+        assert_eq!(virtualized(0, 320.0).columns(), 3);
+        assert_eq!(virtualized(0, 319.0).columns(), 2);
+    }
+
+    #[test]
+    fn single_column_when_narrower_than_a_column() {
+        assert_eq!(virtualized(0, 50.0).columns(), 1);
+        assert_eq!(collection(0).columns(), 1);
+
+        let mut zero_width = virtualized(0, 320.0);
+        zero_width.set_column_width(0.0);
+        zero_width.set_gap(0.0);
+        assert_eq!(zero_width.columns(), 1);
+    }
+
+    #[test]
+    fn all_the_cards_are_rendered_when_not_virtualized() {
+        assert_eq!(collection(0).visible_range(), 0..0);
+        assert_eq!(collection(5).visible_range(), 0..5);
+    }
+
+    #[test]
+    fn empty_virtualized_collection() {
+        assert_eq!(virtualized(0, 320.0).visible_range(), 0..0);
+        assert_eq!(virtualized(0, 320.0).scroll_top_to_reveal(0), None);
+    }
+
+    #[test]
+    fn visible_rows_with_overscan() {
+        let mut collection = virtualized(30, 320.0);
+        // rows 0 to 2 are visible, and 1 more row below
+        assert_eq!(collection.visible_range(), 0..12);
+        // rows 3 to 5 are visible, and 1 more row on each side
+        collection.scroll_top = 330.0;
+        assert_eq!(collection.visible_range(), 6..21);
+    }
+
+    #[test]
+    fn scrolled_past_the_end_renders_the_last_row() {
+        let mut collection = virtualized(10, 320.0);
+        collection.scroll_top = 10_000.0;
+        assert_eq!(collection.visible_range(), 9..10);
+    }
+
+    #[test]
+    fn zero_row_height_is_not_virtualized() {
+        let mut collection = virtualized(10, 320.0);
+        collection.set_virtualized(0.0, 300.0);
+        collection.set_gap(0.0);
+        collection.scroll_top = 500.0;
+        assert_eq!(collection.visible_range(), 0..10);
+        assert_eq!(collection.scroll_top_to_reveal(9), None);
+    }
+
+    #[test]
+    fn scroll_to_reveal_the_row_of_the_card() {
+        let mut collection = virtualized(10, 50.0);
+        assert_eq!(collection.scroll_top_to_reveal(0), None);
+        assert_eq!(collection.scroll_top_to_reveal(1), None);
+        // the row of the 4th card is below the visible area
+        assert_eq!(collection.scroll_top_to_reveal(3), Some(330.0));
+        collection.scroll_top = 330.0;
+        assert_eq!(collection.scroll_top_to_reveal(3), None);
+        // back up to the first row
+        assert_eq!(collection.scroll_top_to_reveal(0), Some(0.0));
+    }
+}
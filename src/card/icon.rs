@@ -0,0 +1,296 @@
+use super::Card;
+use crate::card;
+use crate::custom_element::{
+    register_custom_element, AttributeDef, CustomElement, DeclaredAttributes,
+};
+use crate::scope::Scope;
+use crate::Status;
+use crate::Theme;
+use css_colors::Color;
+use sauron::{
+    dom::WebComponent,
+    html::{attributes::*, *},
+    svg::attributes::{d, view_box, xmlns},
+    *,
+};
+
+pub(crate) const COMPONENT_NAME: &str = "sfui-icon-card";
+
+pub enum Msg<XMSG> {
+    CardMsg(card::Msg<XMSG>),
+}
+
+/// A card with a large icon, a heading and a short text
+pub struct IconCard<XMSG> {
+    card: Card<XMSG>,
+    /// the path data of the icon, drawn in a 24x24 viewbox
+    icon: Option<String>,
+    heading: Option<String>,
+    text: Option<String>,
+    status: Option<Status>,
+    scope: Scope,
+}
+
+impl<XMSG> Default for IconCard<XMSG>
+where
+    XMSG: 'static,
+{
+    fn default() -> Self {
+        Self {
+            card: Card::default(),
+            icon: None,
+            heading: None,
+            text: None,
+            status: None,
+            scope: Scope::new(COMPONENT_NAME),
+        }
+    }
+}
+
+impl<XMSG> IconCard<XMSG>
+where
+    XMSG: 'static,
+{
+    pub fn new(icon: &str, heading: &str, text: &str) -> Self {
+        Self {
+            icon: Some(icon.to_string()),
+            heading: Some(heading.to_string()),
+            text: Some(text.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.set_theme(theme);
+        self
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.card.set_theme(theme);
+    }
+
+    /// color the icon and the frame with the status
+    pub fn set_status(&mut self, status: Status) {
+        self.status = Some(status);
+        self.card.set_status(status);
+    }
+
+    pub fn clear_status(&mut self) {
+        self.status = None;
+        self.card.clear_status();
+    }
+
+    /// the svg path data of the icon, drawn in a 24x24 viewbox
+    pub fn set_icon(&mut self, path: &str) {
+        self.icon = Some(path.to_string());
+    }
+
+    pub fn clear_icon(&mut self) {
+        self.icon = None;
+    }
+
+    pub fn set_heading(&mut self, heading: &str) {
+        self.heading = Some(heading.to_string());
+    }
+
+    pub fn clear_heading(&mut self) {
+        self.heading = None;
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+    }
+
+    pub fn clear_text(&mut self) {
+        self.text = None;
+    }
+
+    fn view_icon(&self) -> Node<XMSG> {
+        div(
+            [class(self.scope.class("icon"))],
+            self.icon
+                .iter()
+                .map(|icon| {
+                    svg(
+                        [
+                            xmlns("http://www.w3.org/2000/svg"),
+                            view_box([0, 0, 24, 24]),
+                        ],
+                        [path([d(icon)], [])],
+                    )
+                })
+                .chain(
+                    self.card
+                        .slotted
+                        .then(|| node! { <slot name="icon"></slot> }),
+                ),
+        )
+    }
+}
+
+impl<XMSG> Container<Msg<XMSG>, XMSG> for IconCard<XMSG>
+where
+    XMSG: 'static,
+{
+    fn update(&mut self, msg: Msg<XMSG>) -> Effects<Msg<XMSG>, XMSG> {
+        match msg {
+            Msg::CardMsg(cmsg) => {
+                let effects =
+                    <Card<XMSG> as Container<card::Msg<XMSG>, XMSG>>::update(&mut self.card, cmsg);
+                effects.map_msg(Msg::CardMsg)
+            }
+        }
+    }
+
+    fn view(&self, content: impl IntoIterator<Item = Node<XMSG>>) -> Node<Msg<XMSG>> {
+        let icon_card = div(
+            [
+                class(self.scope.class("icon_card")),
+                if let Some(ref status) = self.status {
+                    class(self.scope.class(status.class_name()))
+                } else {
+                    empty_attr()
+                },
+            ],
+            [
+                self.view_icon(),
+                view_if(
+                    self.heading.is_some(),
+                    h3(
+                        [class(self.scope.class("heading"))],
+                        self.heading.iter().map(text),
+                    ),
+                ),
+                view_if(
+                    self.text.is_some(),
+                    p(
+                        [class(self.scope.class("text"))],
+                        self.text.iter().map(text),
+                    ),
+                ),
+            ],
+        );
+        self.card
+            .view([icon_card].into_iter().chain(content))
+            .map_msg(Msg::CardMsg)
+    }
+
+    fn append_child(&mut self, child: Node<XMSG>) {
+        self.card.append_child(child)
+    }
+
+    fn style(&self) -> Vec<String> {
+        let theme = &self.card.theme;
+        let base = &theme.controls;
+
+        let main = jss! {
+            ".icon_card": {
+                display: "flex",
+                flex_direction: "column",
+                align_items: "center",
+                text_align: "center",
+                gap: px(8),
+            },
+
+            ".icon svg": {
+                width: px(64),
+                height: px(64),
+                fill: theme.primary_color.clone(),
+                filter: format!("drop-shadow({} {})", px([0, 0, 4]), theme.accent_shadow),
+            },
+
+            ".heading": {
+                margin: 0,
+                color: theme.primary_color.clone(),
+            },
+
+            ".text": {
+                margin: 0,
+                color: base.button_text_color.clone(),
+            },
+
+            ".error .icon svg": {
+                fill: theme.error().to_css(),
+            },
+
+            ".success .icon svg": {
+                fill: theme.success().to_css(),
+            },
+
+            ".info .icon svg": {
+                fill: theme.info().to_css(),
+            },
+
+            ".warning .icon svg": {
+                fill: theme.warning().to_css(),
+            },
+        };
+
+        [
+            vec![self.scope.style(&main)],
+            <Card<XMSG> as Container<card::Msg<XMSG>, XMSG>>::style(&self.card),
+        ]
+        .concat()
+    }
+}
+
+impl<XMSG> DeclaredAttributes for IconCard<XMSG>
+where
+    XMSG: 'static,
+{
+    fn attributes() -> Vec<AttributeDef<Self>> {
+        vec![
+            AttributeDef::theme_primary(|app: &Self| app.card.theme.clone(), Self::set_theme),
+            AttributeDef::theme_background(|app: &Self| app.card.theme.clone(), Self::set_theme),
+            AttributeDef::clearable_enumerated("status", Self::set_status, Self::clear_status),
+            AttributeDef::clearable(
+                "icon",
+                |app: &mut Self, path: String| app.set_icon(&path),
+                Self::clear_icon,
+            ),
+            // not `title`, which is the native tooltip of the element
+            AttributeDef::clearable(
+                "heading",
+                |app: &mut Self, heading: String| app.set_heading(&heading),
+                Self::clear_heading,
+            ),
+            AttributeDef::clearable(
+                "text",
+                |app: &mut Self, text: String| app.set_text(&text),
+                Self::clear_text,
+            ),
+        ]
+    }
+}
+
+impl<XMSG> WebComponent<Msg<XMSG>> for IconCard<XMSG>
+where
+    XMSG: 'static,
+{
+    /// what attributes this component is interested in
+    fn observed_attributes() -> Vec<&'static str> {
+        Self::attribute_names()
+    }
+
+    /// called when any of the attributes in observed_attributes is changed
+    fn attribute_changed(
+        program: Program<Self, Msg<XMSG>>,
+        attr_name: &str,
+        _old_value: Option<String>,
+        new_value: Option<String>,
+    ) {
+        program.app_mut().apply_attribute(attr_name, new_value);
+    }
+
+    fn connected_callback(&mut self) {}
+    fn disconnected_callback(&mut self) {}
+    fn adopted_callback(&mut self) {}
+}
+
+impl CustomElement<Msg<()>> for IconCard<()> {
+    fn use_slots(&mut self) {
+        self.card.slotted = true;
+    }
+}
+
+register_custom_element!(COMPONENT_NAME, IconCardCustomElement, IconCard<()>, Msg<()>);
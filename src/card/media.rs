@@ -0,0 +1,45 @@
+use crate::custom_element::AttributeValues;
+use std::str::FromStr;
+
+/// How the media image of a card is revealed once it is loaded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaReveal {
+    /// the image is displayed as soon as it is loaded
+    None,
+    /// the image fades in, this is the default
+    Fade,
+    /// the image is assembled slice by slice with the `Dice` animation
+    Dice,
+}
+
+/// The loading state of the media image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum MediaState {
+    Loading,
+    Loaded,
+    /// the image could not be loaded, a fallback is displayed instead
+    Failed,
+}
+
+impl Default for MediaReveal {
+    fn default() -> Self {
+        MediaReveal::Fade
+    }
+}
+
+impl FromStr for MediaReveal {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "none" => Ok(MediaReveal::None),
+            "fade" => Ok(MediaReveal::Fade),
+            "dice" => Ok(MediaReveal::Dice),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for MediaReveal {
+    const VALUES: &'static [&'static str] = &["none", "fade", "dice"];
+}
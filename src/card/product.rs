@@ -0,0 +1,271 @@
+use super::Card;
+use crate::button::{self, Button};
+use crate::card;
+use crate::custom_element::{
+    register_custom_element, AttributeDef, CustomElement, DeclaredAttributes,
+};
+use crate::Status;
+use crate::Theme;
+use sauron::{
+    dom::{Callback, WebComponent},
+    html::{attributes::*, *},
+    *,
+};
+
+pub(crate) const COMPONENT_NAME: &str = "sfui-product-card";
+/// the label of the add to cart button when none is set
+const DEFAULT_ACTION_LABEL: &str = "Add to cart";
+
+pub enum Msg<XMSG> {
+    CardMsg(card::Msg<Msg<XMSG>>),
+    ButtonMsg(button::Msg),
+    AddToCart,
+    External(XMSG),
+}
+
+/// A card displaying a product with its image, name, description and price,
+/// and an add to cart button
+pub struct ProductCard<XMSG> {
+    card: Card<Msg<XMSG>>,
+    add_to_cart: Button<Msg<XMSG>>,
+    add_to_cart_listeners: Vec<Callback<(), XMSG>>,
+}
+
+impl<XMSG> Default for ProductCard<XMSG>
+where
+    XMSG: 'static,
+{
+    fn default() -> Self {
+        let mut add_to_cart = Button::with_label(DEFAULT_ACTION_LABEL);
+        add_to_cart.add_click_listener(|_| Msg::AddToCart);
+        Self {
+            card: Card::default(),
+            add_to_cart,
+            add_to_cart_listeners: vec![],
+        }
+    }
+}
+
+impl<XMSG> ProductCard<XMSG>
+where
+    XMSG: 'static,
+{
+    pub fn new(name: &str, price: &str) -> Self {
+        let mut product = Self::default();
+        product.set_name(name);
+        product.set_price(price);
+        product
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.set_theme(theme);
+        self
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.card.set_theme(theme.clone());
+        self.add_to_cart.set_theme(theme);
+    }
+
+    /// color the frame and the add to cart button with the status
+    pub fn set_status(&mut self, status: Status) {
+        self.card.set_status(status);
+        self.add_to_cart.set_status(status);
+    }
+
+    pub fn clear_status(&mut self) {
+        self.card.clear_status();
+        self.add_to_cart.clear_status();
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.card.set_heading(name);
+    }
+
+    pub fn clear_name(&mut self) {
+        self.card.clear_heading();
+    }
+
+    pub fn set_description(&mut self, description: &str) {
+        self.card.set_description(description);
+    }
+
+    pub fn clear_description(&mut self) {
+        self.card.clear_description();
+    }
+
+    /// the price, formatted as it is displayed, ie: `$10.99`
+    pub fn set_price(&mut self, price: &str) {
+        self.card.set_price(price);
+    }
+
+    pub fn clear_price(&mut self) {
+        self.card.clear_price();
+    }
+
+    pub fn set_image(&mut self, url: &str, alt: &str) {
+        self.card.set_media(url, alt);
+    }
+
+    pub fn clear_image(&mut self) {
+        self.card.clear_media();
+    }
+
+    pub fn clear_image_alt(&mut self) {
+        self.card.clear_media_alt();
+    }
+
+    /// the label of the add to cart button
+    pub fn set_action_label(&mut self, label: &str) {
+        self.add_to_cart.set_label(label);
+    }
+
+    /// use the default label of the add to cart button
+    pub fn clear_action_label(&mut self) {
+        self.add_to_cart.set_label(DEFAULT_ACTION_LABEL);
+    }
+
+    pub fn add_to_cart_listener<F>(&mut self, f: F)
+    where
+        F: Fn(()) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.add_to_cart_listeners.push(cb);
+    }
+}
+
+impl<XMSG> Container<Msg<XMSG>, XMSG> for ProductCard<XMSG>
+where
+    XMSG: 'static,
+{
+    fn update(&mut self, msg: Msg<XMSG>) -> Effects<Msg<XMSG>, XMSG> {
+        match msg {
+            Msg::CardMsg(cmsg) => {
+                let effects =
+                    <Card<Msg<XMSG>> as Container<card::Msg<Msg<XMSG>>, Msg<XMSG>>>::update(
+                        &mut self.card,
+                        cmsg,
+                    );
+                effects.localize(Msg::CardMsg)
+            }
+            Msg::ButtonMsg(bmsg) => {
+                let effects = <Button<Msg<XMSG>> as Container<button::Msg, Msg<XMSG>>>::update(
+                    &mut self.add_to_cart,
+                    bmsg,
+                );
+                effects.localize(Msg::ButtonMsg)
+            }
+            Msg::AddToCart => {
+                let external = self
+                    .add_to_cart_listeners
+                    .iter()
+                    .map(|listener| listener.emit(()));
+                Effects::with_external(external)
+            }
+            Msg::External(xmsg) => Effects::with_external([xmsg]),
+        }
+    }
+
+    /// the add to cart button is rendered in the actions of the card
+    fn view(&self, content: impl IntoIterator<Item = Node<XMSG>>) -> Node<Msg<XMSG>> {
+        let add_to_cart = self.add_to_cart.view([]).map_msg(Msg::ButtonMsg);
+        self.card
+            .view_with_actions(
+                content.into_iter().map(|node| node.map_msg(Msg::External)),
+                [add_to_cart],
+            )
+            .map_msg(Msg::CardMsg)
+    }
+
+    fn append_child(&mut self, child: Node<XMSG>) {
+        self.card.append_child(child.map_msg(Msg::External))
+    }
+
+    fn style(&self) -> Vec<String> {
+        [
+            <Card<Msg<XMSG>> as Container<card::Msg<Msg<XMSG>>, Msg<XMSG>>>::style(&self.card),
+            <Button<Msg<XMSG>> as Container<button::Msg, Msg<XMSG>>>::style(&self.add_to_cart),
+        ]
+        .concat()
+    }
+}
+
+impl<XMSG> DeclaredAttributes for ProductCard<XMSG>
+where
+    XMSG: 'static,
+{
+    fn attributes() -> Vec<AttributeDef<Self>> {
+        vec![
+            AttributeDef::theme_primary(|app: &Self| app.card.theme.clone(), Self::set_theme),
+            AttributeDef::theme_background(|app: &Self| app.card.theme.clone(), Self::set_theme),
+            AttributeDef::clearable_enumerated("status", Self::set_status, Self::clear_status),
+            AttributeDef::clearable(
+                "name",
+                |app: &mut Self, name: String| app.set_name(&name),
+                Self::clear_name,
+            ),
+            AttributeDef::clearable(
+                "description",
+                |app: &mut Self, description: String| app.set_description(&description),
+                Self::clear_description,
+            ),
+            AttributeDef::clearable(
+                "price",
+                |app: &mut Self, price: String| app.set_price(&price),
+                Self::clear_price,
+            ),
+            AttributeDef::clearable(
+                "image",
+                |app: &mut Self, url: String| app.card.set_media_url(&url),
+                Self::clear_image,
+            ),
+            AttributeDef::clearable(
+                "image-alt",
+                |app: &mut Self, alt: String| app.card.set_media_alt(&alt),
+                Self::clear_image_alt,
+            ),
+            AttributeDef::clearable(
+                "action-label",
+                |app: &mut Self, label: String| app.set_action_label(&label),
+                Self::clear_action_label,
+            ),
+        ]
+    }
+}
+
+impl<XMSG> WebComponent<Msg<XMSG>> for ProductCard<XMSG>
+where
+    XMSG: 'static,
+{
+    /// what attributes this component is interested in
+    fn observed_attributes() -> Vec<&'static str> {
+        Self::attribute_names()
+    }
+
+    /// called when any of the attributes in observed_attributes is changed
+    fn attribute_changed(
+        program: Program<Self, Msg<XMSG>>,
+        attr_name: &str,
+        _old_value: Option<String>,
+        new_value: Option<String>,
+    ) {
+        program.app_mut().apply_attribute(attr_name, new_value);
+    }
+
+    fn connected_callback(&mut self) {}
+    fn disconnected_callback(&mut self) {}
+    fn adopted_callback(&mut self) {}
+}
+
+impl CustomElement<Msg<()>> for ProductCard<()> {
+    fn use_slots(&mut self) {
+        self.card.slotted = true;
+    }
+}
+
+register_custom_element!(
+    COMPONENT_NAME,
+    ProductCardCustomElement,
+    ProductCard<()>,
+    Msg<()>
+);
@@ -0,0 +1,540 @@
+//! A reusable custom element shim for the components in this crate.
+//!
+//! Any component which implements `Container` + `WebComponent` + `Default` can be
+//! registered as an html custom element using the `register_custom_element!` macro,
+//! which generates the wasm-bindgen glue and a `register` function for it.
+use crate::Theme;
+use sauron::{dom::WebComponent, wasm_bindgen::JsCast, *};
+use std::str::FromStr;
+
+/// A component that can be used as an html custom element
+pub trait CustomElement<MSG>:
+    Application<MSG> + WebComponent<MSG> + DeclaredAttributes + Default
+where
+    MSG: 'static,
+{
+    /// called right after the component is created for a host element.
+    /// Components that display the light dom children of the host element
+    /// should render `<slot>`s from here on, the host element keeps ownership of its children.
+    fn use_slots(&mut self) {}
+
+    /// called when the host element is connected to the document, before the component is mounted.
+    /// Components can inspect where the host element is placed, such as its ancestors.
+    fn host_connected(&mut self, _host: &web_sys::Element) {}
+}
+
+/// Types parsed from a fixed set of attribute values.
+/// This is used for describing the allowed values of an attribute in the generated
+/// custom element manifests, and should list every value accepted by its `FromStr`.
+pub trait AttributeValues {
+    const VALUES: &'static [&'static str];
+}
+
+/// The type of the value of an attribute when accessed as a js property of the custom element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeKind {
+    String,
+    Number,
+    /// true when the attribute is present
+    Boolean,
+}
+
+/// Declaration of an attribute of a custom element:
+/// the attribute name and how its value is parsed and applied to the component.
+pub struct AttributeDef<APP> {
+    /// the html attribute name
+    pub name: &'static str,
+    /// the type of the js property for this attribute
+    pub kind: AttributeKind,
+    /// the allowed values of this attribute, empty if it accepts any value
+    pub values: &'static [&'static str],
+    apply: Box<dyn Fn(&mut APP, Option<String>)>,
+}
+
+impl<APP> AttributeDef<APP>
+where
+    APP: 'static,
+{
+    /// an attribute which value is parsed using `FromStr`,
+    /// values that can not be parsed and removed attributes are ignored
+    pub fn parsed<T>(name: &'static str, setter: fn(&mut APP, T)) -> Self
+    where
+        T: FromStr + 'static,
+    {
+        Self {
+            name,
+            kind: AttributeKind::String,
+            values: &[],
+            apply: Box::new(move |app, value| {
+                if let Some(v) = value.and_then(|v| T::from_str(&v).ok()) {
+                    setter(app, v);
+                }
+            }),
+        }
+    }
+
+    /// an attribute which only accepts the values that `T` can be parsed from
+    pub fn enumerated<T>(name: &'static str, setter: fn(&mut APP, T)) -> Self
+    where
+        T: FromStr + AttributeValues + 'static,
+    {
+        Self {
+            values: T::VALUES,
+            ..Self::parsed(name, setter)
+        }
+    }
+
+    /// an attribute which value is parsed using `FromStr`,
+    /// the value is cleared when the attribute is removed or can not be parsed
+    pub fn clearable<T>(name: &'static str, setter: fn(&mut APP, T), clear: fn(&mut APP)) -> Self
+    where
+        T: FromStr + 'static,
+    {
+        Self {
+            name,
+            kind: AttributeKind::String,
+            values: &[],
+            apply: Box::new(
+                move |app, value| match value.and_then(|v| T::from_str(&v).ok()) {
+                    Some(v) => setter(app, v),
+                    None => clear(app),
+                },
+            ),
+        }
+    }
+
+    /// just like `enumerated`, but the value is cleared when the attribute is removed
+    /// or is not one of the allowed values
+    pub fn clearable_enumerated<T>(
+        name: &'static str,
+        setter: fn(&mut APP, T),
+        clear: fn(&mut APP),
+    ) -> Self
+    where
+        T: FromStr + AttributeValues + 'static,
+    {
+        Self {
+            values: T::VALUES,
+            ..Self::clearable(name, setter, clear)
+        }
+    }
+
+    /// just like `parsed`, but exposed as a number js property
+    pub fn number<T>(name: &'static str, setter: fn(&mut APP, T)) -> Self
+    where
+        T: FromStr + 'static,
+    {
+        Self {
+            kind: AttributeKind::Number,
+            ..Self::parsed(name, setter)
+        }
+    }
+
+    /// the `theme-primary` attribute, combined with the current background color of the theme.
+    /// Colors that can not be parsed and removed attributes are ignored
+    pub fn theme_primary(theme: fn(&APP) -> Theme, set_theme: fn(&mut APP, Theme)) -> Self {
+        Self {
+            name: "theme-primary",
+            kind: AttributeKind::String,
+            values: &[],
+            apply: Box::new(move |app, value| {
+                if let Some(primary) = value {
+                    let background = theme(app).background_color;
+                    if let Ok(theme) = Theme::from_str(&primary, &background) {
+                        set_theme(app, theme);
+                    }
+                }
+            }),
+        }
+    }
+
+    /// the `theme-background` attribute, combined with the current primary color of the theme.
+    /// Colors that can not be parsed and removed attributes are ignored
+    pub fn theme_background(theme: fn(&APP) -> Theme, set_theme: fn(&mut APP, Theme)) -> Self {
+        Self {
+            name: "theme-background",
+            kind: AttributeKind::String,
+            values: &[],
+            apply: Box::new(move |app, value| {
+                if let Some(background) = value {
+                    let primary = theme(app).primary_color;
+                    if let Ok(theme) = Theme::from_str(&primary, &background) {
+                        set_theme(app, theme);
+                    }
+                }
+            }),
+        }
+    }
+
+    /// a boolean attribute, which is true when the attribute is present
+    pub fn flag(name: &'static str, setter: fn(&mut APP, bool)) -> Self {
+        Self {
+            name,
+            kind: AttributeKind::Boolean,
+            values: &[],
+            apply: Box::new(move |app, value| setter(app, value.is_some())),
+        }
+    }
+
+    /// the name of the js property of this attribute, ie: `theme-primary` -> `themePrimary`
+    pub fn property_name(&self) -> String {
+        let mut parts = self.name.split('-');
+        let first = parts.next().unwrap_or_default().to_string();
+        parts.fold(first, |mut acc, part| {
+            let mut chars = part.chars();
+            if let Some(c) = chars.next() {
+                acc.extend(c.to_uppercase());
+                acc.push_str(chars.as_str());
+            }
+            acc
+        })
+    }
+
+    /// apply the new value of this attribute to the component
+    pub fn apply(&self, app: &mut APP, new_value: Option<String>) {
+        (self.apply)(app, new_value)
+    }
+}
+
+/// Components which declare their attributes in one place.
+/// The observed attributes and the attribute changed handler of the `WebComponent`
+/// are derived from it, so they can not drift apart.
+pub trait DeclaredAttributes: Sized + 'static {
+    /// all the attributes of this component
+    fn attributes() -> Vec<AttributeDef<Self>>;
+
+    /// the names of the declared attributes, used as `observed_attributes`
+    fn attribute_names() -> Vec<&'static str> {
+        Self::attributes().iter().map(|attr| attr.name).collect()
+    }
+
+    /// apply the value of the changed attribute, unknown attributes are ignored
+    fn apply_attribute(&mut self, attr_name: &str, new_value: Option<String>) {
+        if let Some(attr) = Self::attributes()
+            .into_iter()
+            .find(|attr| attr.name == attr_name)
+        {
+            attr.apply(self, new_value);
+        }
+    }
+}
+
+/// Wraps the program of a component and forwards the custom element lifecycle callbacks
+/// to it
+pub struct WebComponentWrapper<APP, MSG>
+where
+    APP: CustomElement<MSG> + 'static,
+    MSG: 'static,
+{
+    program: Program<APP, MSG>,
+    /// the custom element which hosts the shadow root of the component
+    host: web_sys::Node,
+}
+
+impl<APP, MSG> WebComponentWrapper<APP, MSG>
+where
+    APP: CustomElement<MSG> + 'static,
+    MSG: 'static,
+{
+    /// create the program of the component, this will be mounted into the shadow root of `node`
+    pub fn new(node: JsValue) -> Self {
+        let mount_node: &web_sys::Node = node.unchecked_ref();
+        let mut app = APP::default();
+        app.use_slots();
+        Self {
+            program: Program::new(
+                app,
+                mount_node,
+                MountAction::Append,
+                MountTarget::ShadowRoot,
+            ),
+            host: mount_node.clone(),
+        }
+    }
+
+    /// define a js property on the host element for each of the declared attributes.
+    /// Properties are backed by the attributes, so setting a property goes through the same
+    /// setter as `attribute_changed` and is reflected back to the attribute.
+    /// Components with theme attributes also get a `theme` property which accepts
+    /// `{primary, background}`.
+    pub fn define_properties(node: &JsValue) {
+        let element: &web_sys::Element = node.unchecked_ref();
+        let attributes = APP::attributes();
+        for attr in attributes.iter() {
+            let prop = attr.property_name();
+            let pending = take_own_property(element, &prop);
+            define_attribute_property(element, &prop, attr.name, attr.kind);
+            set_pending_property(element, &prop, pending);
+        }
+        let has_theme = THEME_PROPERTY_KEYS
+            .iter()
+            .all(|(_, name)| attributes.iter().any(|attr| attr.name == *name));
+        if has_theme {
+            let pending = take_own_property(element, "theme");
+            define_theme_property(element);
+            set_pending_property(element, "theme", pending);
+        }
+    }
+
+    /// the attributes the component is interested in, as a js array
+    pub fn observed_attributes() -> JsValue {
+        let attributes = APP::observed_attributes();
+        serde_wasm_bindgen::to_value(&attributes).expect("must be serde")
+    }
+
+    pub fn attribute_changed(&self, attr_name: &str, old_value: JsValue, new_value: JsValue) {
+        APP::attribute_changed(
+            self.program.clone(),
+            attr_name,
+            old_value.as_string(),
+            new_value.as_string(),
+        );
+    }
+
+    pub fn connected_callback(&mut self) {
+        self.program
+            .app_mut()
+            .host_connected(self.host.unchecked_ref());
+        self.program.mount();
+
+        let static_style = <APP as Application<MSG>>::stylesheet().join("");
+        self.program.inject_style_to_mount(&static_style);
+        let dynamic_style = <APP as Application<MSG>>::style(&self.program.app()).join("");
+        self.program.inject_style_to_mount(&dynamic_style);
+
+        self.program.app_mut().connected_callback();
+    }
+
+    pub fn disconnected_callback(&mut self) {
+        self.program.app_mut().disconnected_callback()
+    }
+
+    pub fn adopted_callback(&mut self) {
+        self.program.app_mut().adopted_callback()
+    }
+
+    /// children appended to the custom element stays in the light dom of the host element
+    /// and are projected into the component through its slots.
+    pub fn append_child(&mut self, child: JsValue) {
+        let child_node: web_sys::Node = child.unchecked_into();
+        // `appendChild` of the host element is routed here, so use `insertBefore` instead
+        self.host
+            .insert_before(&child_node, None)
+            .expect("must append child..");
+    }
+}
+
+/// the `theme` property keys and the attributes they are backed by
+pub(crate) const THEME_PROPERTY_KEYS: [(&str, &str); 2] = [
+    ("primary", "theme-primary"),
+    ("background", "theme-background"),
+];
+
+/// take the value of a property which was set on the element before it was upgraded,
+/// the property is deleted so it doesn't get overwritten by the accessor defined for it
+fn take_own_property(element: &web_sys::Element, prop: &str) -> Option<JsValue> {
+    let object: &js_sys::Object = element.unchecked_ref();
+    let key = JsValue::from_str(prop);
+    if object.has_own_property(&key) {
+        let value = js_sys::Reflect::get(object, &key).expect("must get property");
+        js_sys::Reflect::delete_property(object, &key).expect("must delete property");
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// set the value taken before the upgrade again, this time through the accessor
+fn set_pending_property(element: &web_sys::Element, prop: &str, pending: Option<JsValue>) {
+    if let Some(value) = pending {
+        js_sys::Reflect::set(element, &JsValue::from_str(prop), &value).expect("must set");
+    }
+}
+
+fn define_property(element: &web_sys::Element, prop: &str, get: JsValue, set: JsValue) {
+    let descriptor = js_sys::Object::new();
+    js_sys::Reflect::set(&descriptor, &JsValue::from_str("get"), &get).expect("must set");
+    js_sys::Reflect::set(&descriptor, &JsValue::from_str("set"), &set).expect("must set");
+    js_sys::Reflect::set(
+        &descriptor,
+        &JsValue::from_str("configurable"),
+        &JsValue::TRUE,
+    )
+    .expect("must set");
+    js_sys::Object::define_property(
+        element.unchecked_ref(),
+        &JsValue::from_str(prop),
+        &descriptor,
+    );
+}
+
+fn define_attribute_property(
+    element: &web_sys::Element,
+    prop: &str,
+    attr_name: &'static str,
+    kind: AttributeKind,
+) {
+    let get_element = element.clone();
+    let get: Closure<dyn Fn() -> JsValue> = Closure::new(move || {
+        let value = get_element.get_attribute(attr_name);
+        match kind {
+            AttributeKind::String => value.map(JsValue::from).unwrap_or(JsValue::NULL),
+            AttributeKind::Number => value
+                .and_then(|v| v.parse::<f64>().ok())
+                .map(JsValue::from)
+                .unwrap_or(JsValue::NULL),
+            AttributeKind::Boolean => JsValue::from_bool(value.is_some()),
+        }
+    });
+    let set_element = element.clone();
+    let set: Closure<dyn Fn(JsValue)> = Closure::new(move |value: JsValue| {
+        let result = match kind {
+            AttributeKind::Boolean if value.is_truthy() => set_element.set_attribute(attr_name, ""),
+            AttributeKind::Boolean => set_element.remove_attribute(attr_name),
+            _ if value.is_null() || value.is_undefined() => set_element.remove_attribute(attr_name),
+            _ => {
+                let value = value
+                    .as_string()
+                    .or_else(|| value.as_f64().map(|v| v.to_string()))
+                    .unwrap_or_default();
+                set_element.set_attribute(attr_name, &value)
+            }
+        };
+        result.expect("must set attribute");
+    });
+    define_property(element, prop, get.into_js_value(), set.into_js_value());
+}
+
+/// `theme` property which accepts and returns `{primary, background}`
+fn define_theme_property(element: &web_sys::Element) {
+    let get_element = element.clone();
+    let get: Closure<dyn Fn() -> JsValue> = Closure::new(move || {
+        let theme = js_sys::Object::new();
+        for (key, attr_name) in THEME_PROPERTY_KEYS {
+            if let Some(value) = get_element.get_attribute(attr_name) {
+                js_sys::Reflect::set(&theme, &JsValue::from_str(key), &JsValue::from(value))
+                    .expect("must set");
+            }
+        }
+        theme.into()
+    });
+    let set_element = element.clone();
+    let set: Closure<dyn Fn(JsValue)> = Closure::new(move |theme: JsValue| {
+        for (key, attr_name) in THEME_PROPERTY_KEYS {
+            let value = js_sys::Reflect::get(&theme, &JsValue::from_str(key))
+                .ok()
+                .and_then(|v| v.as_string());
+            if let Some(value) = value {
+                set_element
+                    .set_attribute(attr_name, &value)
+                    .expect("must set attribute");
+            }
+        }
+    });
+    define_property(element, "theme", get.into_js_value(), set.into_js_value());
+}
+
+/// dispatch the custom event `name` on the host element of a component, so the page
+/// can listen to it. The event doesn't bubble, so the events of the nested elements
+/// are not mistaken for the events of their ancestors
+pub(crate) fn dispatch_host_event(host: &web_sys::Element, name: &str, detail: &JsValue) {
+    let mut init = web_sys::CustomEventInit::new();
+    init.detail(detail);
+    let event =
+        web_sys::CustomEvent::new_with_event_init_dict(name, &init).expect("must create event");
+    host.dispatch_event(&event).expect("must dispatch event");
+}
+
+/// the `{width, height}` detail of the resize events dispatched on the host elements
+pub(crate) fn size_detail(width: f32, height: f32) -> JsValue {
+    let detail = js_sys::Object::new();
+    js_sys::Reflect::set(&detail, &"width".into(), &width.into()).expect("must set width");
+    js_sys::Reflect::set(&detail, &"height".into(), &height.into()).expect("must set height");
+    detail.into()
+}
+
+/// Generate the wasm-bindgen custom element struct `$name` for the component `$app`
+/// and a `register` function which defines it with the tag `$tag`.
+///
+/// The calling module is expected to have `sauron::*` in scope.
+///
+/// ```ignore
+/// register_custom_element!("sfui-frame", FrameCustomElement, Frame<()>, Msg<()>);
+/// ```
+macro_rules! register_custom_element {
+    ($tag:expr, $name:ident, $app:ty, $msg:ty) => {
+        #[wasm_bindgen]
+        pub struct $name {
+            wrapper: $crate::custom_element::WebComponentWrapper<$app, $msg>,
+        }
+
+        #[wasm_bindgen]
+        impl $name {
+            #[wasm_bindgen(constructor)]
+            pub fn new(node: JsValue) -> Self {
+                Self {
+                    wrapper: $crate::custom_element::WebComponentWrapper::new(node),
+                }
+            }
+
+            #[wasm_bindgen(getter, static_method_of = Self, js_name = observedAttributes)]
+            pub fn observed_attributes() -> JsValue {
+                $crate::custom_element::WebComponentWrapper::<$app, $msg>::observed_attributes()
+            }
+
+            #[wasm_bindgen(method, js_name = attributeChangedCallback)]
+            pub fn attribute_changed_callback(
+                &self,
+                attr_name: &str,
+                old_value: JsValue,
+                new_value: JsValue,
+            ) {
+                self.wrapper
+                    .attribute_changed(attr_name, old_value, new_value);
+            }
+
+            #[wasm_bindgen(method, js_name = connectedCallback)]
+            pub fn connected_callback(&mut self) {
+                self.wrapper.connected_callback();
+            }
+
+            #[wasm_bindgen(method, js_name = disconnectedCallback)]
+            pub fn disconnected_callback(&mut self) {
+                self.wrapper.disconnected_callback();
+            }
+
+            #[wasm_bindgen(method, js_name = adoptedCallback)]
+            pub fn adopted_callback(&mut self) {
+                self.wrapper.adopted_callback();
+            }
+
+            #[wasm_bindgen(method, js_name = appendChild)]
+            pub fn append_child(&mut self, child: JsValue) {
+                self.wrapper.append_child(child);
+            }
+
+            pub fn register() {
+                let constructor: Closure<dyn FnMut(JsValue)> = Closure::new(|node: JsValue| {
+                    $crate::custom_element::WebComponentWrapper::<$app, $msg>::define_properties(
+                        &node,
+                    );
+                    let new: Closure<dyn FnMut(JsValue) -> Self> =
+                        Closure::new(|node: JsValue| Self::new(node));
+                    js_sys::Reflect::set(&node, &JsValue::from_str("new"), &new.into_js_value())
+                        .unwrap_throw();
+                });
+                sauron::dom::register_web_component(
+                    $tag,
+                    constructor.into_js_value(),
+                    Self::observed_attributes(),
+                );
+            }
+        }
+
+        pub fn register() {
+            $name::register()
+        }
+    };
+}
+
+pub(crate) use register_custom_element;
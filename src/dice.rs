@@ -1,42 +1,97 @@
+use crate::animation::{AnimationLoop, ANIMATION_FRAME_EVENT};
+use crate::custom_element::{
+    self, register_custom_element, AttributeDef, CustomElement, DeclaredAttributes,
+};
+use crate::observer::{SizeObserver, VisibilityObserver, RESIZE_EVENT, VISIBLE_EVENT};
 use crate::Theme;
 use async_trait::async_trait;
 use sauron::wasm_bindgen::JsCast;
 use sauron::{
-    dom::{spawn_local, Callback},
+    dom::{spawn_local, Callback, WebComponent},
     html::{attributes::*, events::*, *},
     *,
 };
+use std::str::FromStr;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::HtmlAudioElement;
 
-const COMPONENT_NAME: &str = "sfui-dice";
+pub use pattern::{Easing, RevealPattern};
+
+/// the event dispatched on `sfui-dice` when the animation completes,
+/// with the final state `"revealed"` or `"hidden"` as its detail
+pub const ANIMATION_END_EVENT: &str = "sfui-animation-end";
+pub use trigger::{DiceState, DiceTrigger};
+
+mod pattern;
+mod trigger;
+
+pub(crate) const COMPONENT_NAME: &str = "sfui-dice";
 
 #[derive(Clone, Debug)]
 pub enum Msg<XMSG> {
+    /// reveal the content slice by slice
     AnimateIn,
+    /// remove the content slice by slice, in the reverse order of the reveal
+    AnimateOut,
     StopAnimation,
-    NextAnimation(f64, f64),
+    /// an animation frame of the running animation
+    NextAnimation,
     External(XMSG),
     ContainerMounted(MountEvent),
+    /// the content of the dice is resized, such as when its images are loaded
+    ContainerResized,
+    /// the elements assigned to the slot of the content changed
+    SlotChanged,
     AudioMounted(web_sys::Node),
+    /// the dice scrolled into view
+    Visible,
 }
 
 pub struct Properties {
     //slice_size size should be square
     slice_size: f32,
     gap: f32,
+    /// the order in which the slices are revealed
+    pattern: RevealPattern,
+    easing: Easing,
+    /// the duration of the whole animation in ms, when not set it is derived from the number
+    /// of slices, one slice per frame and at most 500ms
+    duration: Option<f64>,
+    /// draw the slice grid and the progress of the animation over the content
+    debug: bool,
 }
 
 pub struct Dice<XMSG> {
     audio_src: String,
     audio: Option<HtmlAudioElement>,
+    /// play the typing sound while animating
+    sound: bool,
     click_listeners: Vec<Callback<MouseEvent, XMSG>>,
     properties: Properties,
-    is_animating: bool,
+    state: DiceState,
+    /// what starts the reveal animation
+    trigger: DiceTrigger,
+    visibility_observer: Option<VisibilityObserver>,
+    size_observer: Option<SizeObserver>,
+    /// drives the running animation, the animation stops when it is dropped
+    animation: Option<AnimationLoop>,
+    /// the duration of the running animation in ms
+    animation_duration: f64,
+    /// the element containing the content, the animation frames are dispatched on it
+    container: Option<web_sys::Element>,
+    animation_listeners: Vec<Callback<DiceState, XMSG>>,
+    /// the `sfui-dice` element, which the events of the dice are dispatched on
+    host: Option<web_sys::Element>,
     theme: Theme,
     limit: usize,
+    /// the rank of each slice in the reveal order of the current animation
+    ranks: Vec<usize>,
     width: Option<f32>,
     height: Option<f32>,
+    children: Vec<Node<XMSG>>,
+    /// project the light dom children of `sfui-dice` into the container using a slot.
+    /// Note: slotted content can only be projected once, so it is not visible in the slices
+    slotted: bool,
 }
 
 impl<XMSG> Dice<XMSG>
@@ -47,18 +102,181 @@ where
         let slice_size = 40.0;
         let gap = 1.0;
 
-        let properties = Properties { slice_size, gap };
+        let properties = Properties {
+            slice_size,
+            gap,
+            pattern: RevealPattern::default(),
+            easing: Easing::default(),
+            duration: None,
+            debug: false,
+        };
 
         Dice {
             audio_src: "sounds/typing.mp3".to_string(),
             audio: None,
+            sound: false,
             click_listeners: vec![],
             properties,
-            is_animating: false,
+            state: DiceState::Revealed,
+            trigger: DiceTrigger::default(),
+            visibility_observer: None,
+            size_observer: None,
+            animation: None,
+            animation_duration: 0.0,
+            container: None,
+            animation_listeners: vec![],
+            host: None,
             theme: Theme::default(),
             limit: 0,
+            ranks: vec![],
             width: None,
             height: None,
+            children: vec![],
+            slotted: false,
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn with_trigger(mut self, trigger: DiceTrigger) -> Self {
+        self.set_trigger(trigger);
+        self
+    }
+
+    /// what starts the reveal animation, the content is hidden until it is triggered
+    /// unless it is revealed on click
+    pub fn set_trigger(&mut self, trigger: DiceTrigger) {
+        self.trigger = trigger;
+        self.state = match trigger {
+            DiceTrigger::Click => DiceState::Revealed,
+            _ => DiceState::Hidden,
+        };
+    }
+
+    pub fn state(&self) -> DiceState {
+        self.state
+    }
+
+    /// listen to the completion of the animations,
+    /// this is called with either `DiceState::Revealed` or `DiceState::Hidden`
+    pub fn add_animation_listener<F>(&mut self, f: F)
+    where
+        F: Fn(DiceState) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.animation_listeners.push(cb);
+    }
+
+    /// reveal the content slice by slice
+    pub fn animate_in(&mut self) -> Effects<Msg<XMSG>, XMSG> {
+        self.start_animation(DiceState::Revealing)
+    }
+
+    /// remove the content slice by slice, in the reverse order of the reveal
+    pub fn animate_out(&mut self) -> Effects<Msg<XMSG>, XMSG> {
+        self.start_animation(DiceState::Hiding)
+    }
+
+    fn start_animation(&mut self, state: DiceState) -> Effects<Msg<XMSG>, XMSG> {
+        let duration = self.properties.duration.unwrap_or_else(|| {
+            let interval = 1_000.0 / 60.0;
+            let real_duration = interval * self.content_len() as f64;
+            let timeout = 500.0;
+            real_duration.min(timeout)
+        });
+        // hiding keeps the order of the last reveal, so it is played in reverse
+        if state == DiceState::Revealing || self.ranks.len() != self.content_len() {
+            let (slice_x, slice_y) = self.slices();
+            self.ranks = self.properties.pattern.ranks(slice_x, slice_y);
+        }
+        self.state = state;
+
+        // there is nothing to slice when the content is not mounted or has no size yet
+        let Some(container) = self.container.as_ref().filter(|_| self.content_len() > 0) else {
+            return Effects::with_local([Msg::StopAnimation]);
+        };
+        self.animation_duration = duration;
+        self.animation = Some(AnimationLoop::start(container));
+
+        if let Some(audio) = self.audio.as_ref().filter(|_| self.sound) {
+            // the browser refuses to play without a user gesture, or when the sound is missing,
+            // the animation goes on silently then
+            if let Ok(promise) = audio.play() {
+                spawn_local(async move {
+                    let _ = JsFuture::from(promise).await;
+                });
+            }
+        }
+
+        Effects::none()
+    }
+
+    pub fn with_pattern(mut self, pattern: RevealPattern) -> Self {
+        self.set_pattern(pattern);
+        self
+    }
+
+    /// the order in which the slices are revealed
+    pub fn set_pattern(&mut self, pattern: RevealPattern) {
+        self.properties.pattern = pattern;
+    }
+
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.properties.easing = easing;
+    }
+
+    /// the duration of the whole animation in ms
+    pub fn set_duration(&mut self, duration: f64) {
+        self.properties.duration = Some(duration);
+    }
+
+    /// the width and height of each square slice, sizes which are not positive are ignored
+    pub fn set_slice_size(&mut self, slice_size: f32) {
+        if slice_size.is_finite() && slice_size > 0.0 {
+            self.properties.slice_size = slice_size;
+        }
+    }
+
+    /// the space between the slices, negative gaps are ignored
+    pub fn set_gap(&mut self, gap: f32) {
+        if gap.is_finite() && gap >= 0.0 {
+            self.properties.gap = gap;
+        }
+    }
+
+    /// draw the slice grid and the progress of the animation over the content,
+    /// which helps when tuning the animation
+    pub fn set_debug(&mut self, debug: bool) {
+        self.properties.debug = debug;
+    }
+
+    pub fn with_sound(mut self, sound: bool) -> Self {
+        self.set_sound(sound);
+        self
+    }
+
+    /// play a typing sound while animating, this is off by default since browsers only
+    /// play sounds which are started by a user gesture such as the click trigger
+    pub fn set_sound(&mut self, sound: bool) {
+        self.sound = sound;
+        if !sound {
+            self.audio = None;
+        }
+    }
+
+    /// whether the slice at `index`, counted row by row, is already revealed
+    fn is_revealed(&self, index: usize) -> bool {
+        let rank = self.ranks.get(index).copied().unwrap_or(index);
+        match self.state {
+            DiceState::Hiding => rank < self.content_len().saturating_sub(self.limit),
+            _ => rank < self.limit,
         }
     }
 
@@ -68,30 +286,13 @@ where
     ) -> Node<Msg<XMSG>> {
         let mut cells = vec![];
         let (slice_x, slice_y) = self.slices();
-        let max = slice_x * slice_y;
-        log::debug!(
-            "computed_width: {}x{}",
-            self.computed_width(),
-            self.computed_height()
-        );
-        log::info!("slice_x: {}, slice_y: {}", slice_x, slice_y);
-        log::info!("max slices: {}", max);
         let mut index = 0;
         let slice_size = self.properties.slice_size;
-        log::info!("limit: {}", self.limit);
         for y in 0..slice_y {
             let top = (self.properties.slice_size + self.properties.gap) * y as f32;
             for x in 0..slice_x {
-                let border = if index < self.limit {
-                    "1px solid red"
-                } else {
-                    "1px solid green"
-                };
-                let visibility = if index < self.limit {
-                    "visible"
-                } else {
-                    "hidden"
-                };
+                let is_revealed = self.is_revealed(index);
+                let visibility = if is_revealed { "visible" } else { "hidden" };
                 {
                     let left = (self.properties.slice_size + self.properties.gap) * x as f32;
                     let cell = div(
@@ -99,7 +300,6 @@ where
                             Self::class_ns("slice"),
                             style! {
                                 position: "absolute",
-                                border: border,
                                 visibility: visibility,
                                 left: px(left),
                                 top: px(top),
@@ -125,6 +325,61 @@ where
         }
         div([Self::class_ns("effects_slices")], cells)
     }
+
+    /// the outline of each slice, highlighted once it is revealed, and the number of revealed slices
+    fn debug_overlay_view(&self) -> Node<Msg<XMSG>> {
+        let (slice_x, slice_y) = self.slices();
+        let slice_size = self.properties.slice_size;
+        let stride = slice_size + self.properties.gap;
+        let is_revealed = |index| match self.state {
+            DiceState::Revealed => true,
+            DiceState::Hidden => false,
+            DiceState::Revealing | DiceState::Hiding => self.is_revealed(index),
+        };
+        let revealed = (0..slice_x * slice_y)
+            .filter(|index| is_revealed(*index))
+            .count();
+        let cells = (0..slice_y).flat_map(|y| {
+            (0..slice_x).map(move |x| {
+                div(
+                    [
+                        Self::class_ns("debug_slice"),
+                        Self::classes_ns_flag([("debug_revealed", is_revealed(y * slice_x + x))]),
+                        style! {
+                            left: px(stride * x as f32),
+                            top: px(stride * y as f32),
+                            width: px(slice_size),
+                            height: px(slice_size),
+                        },
+                    ],
+                    [],
+                )
+            })
+        });
+        div(
+            [Self::class_ns("debug_overlay")],
+            cells.chain([div(
+                [Self::class_ns("debug_progress")],
+                [text(format!(
+                    "{:?} {}/{} ({}x{})",
+                    self.state,
+                    revealed,
+                    slice_x * slice_y,
+                    slice_x,
+                    slice_y
+                ))],
+            )]),
+        )
+    }
+}
+
+impl<XMSG> Default for Dice<XMSG>
+where
+    XMSG: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<XMSG> Container<Msg<XMSG>, XMSG> for Dice<XMSG>
@@ -136,65 +391,119 @@ where
     }
     fn update(&mut self, msg: Msg<XMSG>) -> Effects<Msg<XMSG>, XMSG> {
         match msg {
-            Msg::AnimateIn => {
-                log::info!("starting the animation");
-                let interval = 1_000.0 / 60.0;
-                let real_duration = interval * self.content_len() as f64;
-                let timeout = 500.0;
-                let duration = real_duration.min(timeout);
-                let start = sauron::now();
-                self.is_animating = true;
-
-                if let Some(audio) = &self.audio {
-                    let promise = audio.play().expect("must play");
-                    spawn_local(async move {
-                        JsFuture::from(promise).await.expect("must not error");
-                    });
-                }
-
-                Effects::with_local([Msg::NextAnimation(start, duration)])
-            }
+            Msg::AnimateIn => self.animate_in(),
+            Msg::AnimateOut => self.animate_out(),
             Msg::StopAnimation => {
-                self.is_animating = false;
+                self.animation = None;
+                self.state = match self.state {
+                    DiceState::Revealing => DiceState::Revealed,
+                    DiceState::Hiding => DiceState::Hidden,
+                    state => state,
+                };
                 self.limit = 0;
-                Effects::none()
+                let state = self.state;
+                if let Some(host) = &self.host {
+                    let detail = if state == DiceState::Hidden {
+                        "hidden"
+                    } else {
+                        "revealed"
+                    };
+                    custom_element::dispatch_host_event(
+                        host,
+                        ANIMATION_END_EVENT,
+                        &JsValue::from_str(detail),
+                    );
+                }
+                let external = self
+                    .animation_listeners
+                    .iter()
+                    .map(|listener| listener.emit(state));
+                Effects::with_external(external)
             }
-            Msg::NextAnimation(start, duration) => {
-                log::info!("in next animation... ");
-
-                let timestamp = sauron::now();
+            Msg::NextAnimation => {
+                let Some(animation) = &self.animation else {
+                    return Effects::none();
+                };
 
-                // the time that has elapsed since the start
-                let anim_progress = (timestamp - start).max(0.0);
+                // the time that has elapsed since the start, excluding the time the page was hidden
+                let elapsed = animation.elapsed();
+                let duration = self.animation_duration;
+                let time_fraction = if duration > 0.0 {
+                    elapsed / duration
+                } else {
+                    1.0
+                };
 
                 let content_len = self.content_len();
                 // how many of the slice that are already rendered
-                self.limit = (anim_progress * content_len as f64 / duration).round() as usize;
-                log::info!(
-                    "next animation limit: {}, timestamp: {}",
-                    self.limit,
-                    timestamp
-                );
-
-                let continue_animation = self.limit <= content_len - 1;
+                self.limit = (self.properties.easing.apply(time_fraction) * content_len as f64)
+                    .round() as usize;
+                if let Some(host) = &self.host {
+                    // the fraction of the slices which are revealed or hidden
+                    let progress = if content_len > 0 {
+                        self.limit as f64 / content_len as f64
+                    } else {
+                        1.0
+                    };
+                    custom_element::dispatch_host_event(
+                        host,
+                        ANIMATION_FRAME_EVENT,
+                        &JsValue::from_f64(progress.min(1.0)),
+                    );
+                }
 
-                if continue_animation {
-                    Effects::with_local([Msg::NextAnimation(start, duration)])
-                } else {
+                if self.limit >= content_len {
                     Effects::with_local([Msg::StopAnimation])
+                } else {
+                    Effects::none()
                 }
             }
             Msg::External(xmsg) => Effects::with_external(vec![xmsg]),
             Msg::ContainerMounted(me) => {
                 let container_element: web_sys::Element = me.target_node.unchecked_into();
-                //TODO: this is not accurately computing the container
-                //dimension, maybe because of the container not included the overflow of the
-                //content
-                let rect = container_element.get_bounding_client_rect();
-                self.width = Some(rect.width() as f32);
-                self.height = Some(rect.height() as f32);
+                self.size_observer = Some(SizeObserver::observe(&container_element));
+                self.container = Some(container_element.clone());
+                self.observe_content();
+                self.measure_content();
+                match self.trigger {
+                    DiceTrigger::Mount => self.animate_in(),
+                    DiceTrigger::Visible => {
+                        self.visibility_observer =
+                            Some(VisibilityObserver::observe(&container_element));
+                        Effects::none()
+                    }
+                    DiceTrigger::Click | DiceTrigger::Manual => Effects::none(),
+                }
+            }
+            Msg::SlotChanged => {
+                self.observe_content();
+                self.measure_content();
+                Effects::none()
+            }
+            Msg::ContainerResized => {
+                self.measure_content();
+                if let Some(host) = &self.host {
+                    let detail =
+                        custom_element::size_detail(self.computed_width(), self.computed_height());
+                    custom_element::dispatch_host_event(host, RESIZE_EVENT, &detail);
+                }
+                // the slices are laid out again, so their reveal order is too
+                if self.state.is_animating() && self.ranks.len() != self.content_len() {
+                    let (slice_x, slice_y) = self.slices();
+                    self.ranks = self.properties.pattern.ranks(slice_x, slice_y);
+                }
                 Effects::none()
             }
+            Msg::Visible => {
+                if let Some(host) = &self.host {
+                    custom_element::dispatch_host_event(host, VISIBLE_EVENT, &JsValue::NULL);
+                }
+                if self.state == DiceState::Hidden {
+                    self.animate_in()
+                } else {
+                    Effects::none()
+                }
+            }
             Msg::AudioMounted(node) => {
                 let audio: HtmlAudioElement = node.unchecked_into();
                 self.audio = Some(audio);
@@ -206,10 +515,12 @@ where
     fn view(&self, content: impl IntoIterator<Item = Node<XMSG>>) -> Node<Msg<XMSG>> {
         let content_node = content
             .into_iter()
+            .chain(self.children.clone().into_iter())
             .map(|node| node.map_msg(Msg::External))
             .collect::<Vec<_>>();
 
-        let content_effect = if self.is_animating {
+        let is_animating = self.state.is_animating();
+        let content_effect = if is_animating {
             Some(self.slice_view(content_node.clone()))
         } else {
             None
@@ -218,36 +529,47 @@ where
         div(
             [
                 class(COMPONENT_NAME),
-                Self::classes_ns_flag([("animating", self.is_animating)]),
-                on_click(|_| Msg::AnimateIn),
+                Self::classes_ns_flag([("animating", is_animating)]),
+                if self.trigger == DiceTrigger::Click {
+                    on_click(|_| Msg::AnimateIn)
+                } else {
+                    empty_attr()
+                },
             ],
             [
-                audio(
-                    [
-                        on_mount(|me| Msg::AudioMounted(me.target_node)),
-                        src(&self.audio_src),
-                    ],
-                    [],
+                view_if(
+                    self.sound,
+                    audio(
+                        [
+                            on_mount(|me| Msg::AudioMounted(me.target_node)),
+                            src(&self.audio_src),
+                        ],
+                        [],
+                    ),
                 ),
                 div(
                     [Self::class_ns("effect")],
-                    if let Some(content_effect) = content_effect {
-                        vec![content_effect]
-                    } else {
-                        vec![]
-                    },
+                    content_effect
+                        .into_iter()
+                        .chain(self.properties.debug.then(|| self.debug_overlay_view())),
                 ),
                 div(
                     [
                         Self::class_ns("container"),
                         on_mount(|me| Msg::ContainerMounted(me)),
-                        if self.is_animating {
+                        on(RESIZE_EVENT, |_| Msg::ContainerResized),
+                        on("slotchange", |_| Msg::SlotChanged),
+                        on(VISIBLE_EVENT, |_| Msg::Visible),
+                        on(ANIMATION_FRAME_EVENT, |_| Msg::NextAnimation),
+                        if self.state != DiceState::Revealed {
                             style! { visibility: "hidden" }
                         } else {
                             empty_attr()
                         },
                     ],
-                    content_node,
+                    content_node
+                        .into_iter()
+                        .chain(self.slotted.then(|| node! { <slot></slot> })),
                 ),
             ],
         )
@@ -257,7 +579,9 @@ where
         self.properties.style(&self.theme)
     }
 
-    fn append_child(&mut self, child: Node<XMSG>) {}
+    fn append_child(&mut self, child: Node<XMSG>) {
+        self.children.push(child)
+    }
 }
 
 impl Properties {
@@ -282,21 +606,94 @@ impl Properties {
                 position: "absolute",
             },
 
+            // the overflowing content is measured and sliced too, so it is not clipped
             ".container": {
                 display: "inline-block",
-                overflow: "hidden",
+                overflow: "visible",
+            },
+
+            ".debug_overlay": {
+                position: "absolute",
+                top: 0,
+                left: 0,
+                z_index: 1,
+                pointer_events: "none",
+            },
+
+            ".debug_slice": {
+                position: "absolute",
+                box_sizing: "border-box",
+                border: format!("1px solid {}", theme.secondary_color),
+            },
+
+            ".debug_revealed": {
+                border_color: theme.accent_color.clone(),
+                background_color: theme.accent_shadow.clone(),
+            },
+
+            ".debug_progress": {
+                position: "absolute",
+                top: 0,
+                left: 0,
+                padding: px([2, 4]),
+                font_family: "monospace",
+                font_size: px(11),
+                white_space: "nowrap",
+                color: theme.accent_color.clone(),
+                background_color: theme.background_color.clone(),
             }
         }]
     }
 }
 
 impl<XMSG> Dice<XMSG> {
-    /// slices on x and slices on y
+    /// hide the content at once, stopping the running animation,
+    /// so the content can be revealed again such as when it is replaced
+    pub fn hide(&mut self) {
+        self.animation = None;
+        self.state = DiceState::Hidden;
+        self.limit = 0;
+    }
+
+    /// measure the layout size of the content, including the content overflowing the container.
+    /// The layout size is not affected by the transforms of the dice or its ancestors
+    fn measure_content(&mut self) {
+        if let Some(container) = &self.container {
+            let element: &web_sys::HtmlElement = container.unchecked_ref();
+            self.width = Some(element.offset_width().max(container.scroll_width()) as f32);
+            self.height = Some(element.offset_height().max(container.scroll_height()) as f32);
+        }
+    }
+
+    /// observe the size of each of the content elements too, since the content which
+    /// overflows the container can grow without resizing the container
+    fn observe_content(&self) {
+        let (Some(container), Some(size_observer)) = (&self.container, &self.size_observer) else {
+            return;
+        };
+        let mut child = container.first_element_child();
+        while let Some(element) = child {
+            if let Some(slot) = element.dyn_ref::<web_sys::HtmlSlotElement>() {
+                for assigned in slot.assigned_elements().iter() {
+                    size_observer.observe_also(assigned.unchecked_ref());
+                }
+            } else {
+                size_observer.observe_also(&element);
+            }
+            child = element.next_element_sibling();
+        }
+    }
+
+    /// slices on x and slices on y, the last partial row and column are included
+    /// so the slices covers the whole content
     fn slices(&self) -> (usize, usize) {
         let prop = &self.properties;
+        if prop.slice_size + prop.gap <= 0.0 {
+            return (0, 0);
+        }
         (
-            (self.computed_width() / (prop.slice_size + prop.gap)).round() as usize,
-            (self.computed_height() / (prop.slice_size + prop.gap)).round() as usize,
+            (self.computed_width() / (prop.slice_size + prop.gap)).ceil() as usize,
+            (self.computed_height() / (prop.slice_size + prop.gap)).ceil() as usize,
         )
     }
 
@@ -322,4 +719,63 @@ impl<XMSG> Dice<XMSG> {
     }
 }
 
-pub fn register() {}
+impl<XMSG> DeclaredAttributes for Dice<XMSG>
+where
+    XMSG: 'static,
+{
+    fn attributes() -> Vec<AttributeDef<Self>> {
+        vec![
+            AttributeDef::theme_primary(|app: &Self| app.theme.clone(), Self::set_theme),
+            AttributeDef::theme_background(|app: &Self| app.theme.clone(), Self::set_theme),
+            AttributeDef::enumerated("trigger", Self::set_trigger),
+            AttributeDef::enumerated("pattern", Self::set_pattern),
+            AttributeDef::enumerated("easing", Self::set_easing),
+            AttributeDef::number("duration", Self::set_duration),
+            AttributeDef::number("slice-size", Self::set_slice_size),
+            AttributeDef::number("gap", Self::set_gap),
+            AttributeDef::flag("debug", Self::set_debug),
+            AttributeDef::flag("sound", Self::set_sound),
+        ]
+    }
+}
+
+impl<XMSG> WebComponent<Msg<XMSG>> for Dice<XMSG>
+where
+    XMSG: 'static,
+{
+    /// what attributes this component is interested in
+    fn observed_attributes() -> Vec<&'static str> {
+        Self::attribute_names()
+    }
+
+    /// called when any of the attributes in observed_attributes is changed
+    fn attribute_changed(
+        program: Program<Self, Msg<XMSG>>,
+        attr_name: &str,
+        _old_value: Option<String>,
+        new_value: Option<String>,
+    ) {
+        program.app_mut().apply_attribute(attr_name, new_value);
+    }
+
+    fn connected_callback(&mut self) {}
+
+    /// the observers are created again when the element is mounted on reconnect
+    fn disconnected_callback(&mut self) {
+        self.visibility_observer = None;
+        self.size_observer = None;
+    }
+    fn adopted_callback(&mut self) {}
+}
+
+impl CustomElement<Msg<()>> for Dice<()> {
+    fn use_slots(&mut self) {
+        self.slotted = true;
+    }
+
+    fn host_connected(&mut self, host: &web_sys::Element) {
+        self.host = Some(host.clone());
+    }
+}
+
+register_custom_element!(COMPONENT_NAME, DiceCustomElement, Dice<()>, Msg<()>);
@@ -0,0 +1,286 @@
+use crate::custom_element::AttributeValues;
+use sauron::js_sys;
+use std::str::FromStr;
+
+/// The order in which the slices of the dice are revealed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RevealPattern {
+    /// row by row, from left to right, this is the default
+    RowMajor,
+    /// column by column, from top to bottom
+    ColumnMajor,
+    /// in a random order, shuffled each time the animation starts
+    Random,
+    /// from the outer slices spiraling clockwise to the center
+    Spiral,
+    /// from the center slices to the outer slices
+    FromCenter,
+    /// along the diagonals, from the top left corner to the bottom right corner
+    Diagonal,
+}
+
+/// The rate at which the slices are revealed over the duration of the animation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl Default for RevealPattern {
+    fn default() -> Self {
+        RevealPattern::RowMajor
+    }
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::Linear
+    }
+}
+
+impl RevealPattern {
+    /// the rank in the reveal order of each slice, the slices are indexed row by row
+    pub(crate) fn ranks(&self, columns: usize, rows: usize) -> Vec<usize> {
+        let order = self.order(columns, rows);
+        let mut ranks = vec![0; order.len()];
+        for (rank, index) in order.into_iter().enumerate() {
+            ranks[index] = rank;
+        }
+        ranks
+    }
+
+    /// the index of the slices, in the order they are revealed
+    fn order(&self, columns: usize, rows: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..columns * rows).collect();
+        match self {
+            RevealPattern::RowMajor => (),
+            RevealPattern::ColumnMajor => {
+                order.sort_by_key(|index| (index % columns, index / columns));
+            }
+            RevealPattern::Random => {
+                // fisher-yates shuffle
+                for i in (1..order.len()).rev() {
+                    let j = (js_sys::Math::random() * (i + 1) as f64) as usize;
+                    order.swap(i, j.min(i));
+                }
+            }
+            RevealPattern::Spiral => {
+                order = Self::spiral(columns, rows);
+            }
+            RevealPattern::FromCenter => {
+                let center_x = (columns as f32 - 1.0) / 2.0;
+                let center_y = (rows as f32 - 1.0) / 2.0;
+                let distance = |index: &usize| {
+                    let dx = (index % columns) as f32 - center_x;
+                    let dy = (index / columns) as f32 - center_y;
+                    dx * dx + dy * dy
+                };
+                order.sort_by(|a, b| distance(a).total_cmp(&distance(b)));
+            }
+            RevealPattern::Diagonal => {
+                order.sort_by_key(|index| (index % columns + index / columns, index / columns));
+            }
+        }
+        order
+    }
+
+    /// walk the grid clockwise from the top left corner, shrinking the bounds after each side
+    fn spiral(columns: usize, rows: usize) -> Vec<usize> {
+        let mut order = Vec::with_capacity(columns * rows);
+        if columns == 0 || rows == 0 {
+            return order;
+        }
+        let (mut top, mut bottom) = (0, rows - 1);
+        let (mut left, mut right) = (0, columns - 1);
+        while top <= bottom && left <= right {
+            for x in left..=right {
+                order.push(top * columns + x);
+            }
+            for y in top + 1..=bottom {
+                order.push(y * columns + right);
+            }
+            if top < bottom {
+                for x in (left..right).rev() {
+                    order.push(bottom * columns + x);
+                }
+            }
+            if left < right {
+                for y in (top + 1..bottom).rev() {
+                    order.push(y * columns + left);
+                }
+            }
+            if bottom == 0 || right == 0 {
+                break;
+            }
+            top += 1;
+            left += 1;
+            bottom -= 1;
+            right -= 1;
+        }
+        order
+    }
+}
+
+impl Easing {
+    /// map the elapsed fraction of the duration to the fraction of the revealed slices
+    pub(crate) fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for RevealPattern {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "row-major" => Ok(RevealPattern::RowMajor),
+            "column-major" => Ok(RevealPattern::ColumnMajor),
+            "random" => Ok(RevealPattern::Random),
+            "spiral" => Ok(RevealPattern::Spiral),
+            "from-center" => Ok(RevealPattern::FromCenter),
+            "diagonal" => Ok(RevealPattern::Diagonal),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for RevealPattern {
+    const VALUES: &'static [&'static str] = &[
+        "row-major",
+        "column-major",
+        "random",
+        "spiral",
+        "from-center",
+        "diagonal",
+    ];
+}
+
+impl FromStr for Easing {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "linear" => Ok(Easing::Linear),
+            "ease-in" => Ok(Easing::EaseIn),
+            "ease-out" => Ok(Easing::EaseOut),
+            "ease-in-out" => Ok(Easing::EaseInOut),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for Easing {
+    const VALUES: &'static [&'static str] = &["linear", "ease-in", "ease-out", "ease-in-out"];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// the patterns which don't depend on the random number generator
+    const PATTERNS: [RevealPattern; 5] = [
+        RevealPattern::RowMajor,
+        RevealPattern::ColumnMajor,
+        RevealPattern::Spiral,
+        RevealPattern::FromCenter,
+        RevealPattern::Diagonal,
+    ];
+
+    fn is_permutation(ranks: &[usize]) -> bool {
+        let mut sorted = ranks.to_vec();
+        sorted.sort();
+        sorted == (0..ranks.len()).collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn ranks_of_each_pattern() {
+        assert_eq!(RevealPattern::RowMajor.ranks(2, 2), vec![0, 1, 2, 3]);
+        assert_eq!(
+            RevealPattern::ColumnMajor.ranks(3, 2),
+            vec![0, 2, 4, 1, 3, 5]
+        );
+        assert_eq!(
+            RevealPattern::Spiral.ranks(3, 3),
+            vec![0, 1, 2, 7, 8, 3, 6, 5, 4]
+        );
+        assert_eq!(RevealPattern::FromCenter.ranks(3, 3)[4], 0);
+        assert_eq!(RevealPattern::Diagonal.ranks(3, 2), vec![0, 1, 3, 2, 4, 5]);
+    }
+
+    #[test]
+    fn ranks_of_a_single_row_or_column() {
+        for pattern in PATTERNS {
+            for (columns, rows) in [(1, 1), (1, 5), (5, 1)] {
+                let ranks = pattern.ranks(columns, rows);
+                assert_eq!(ranks.len(), columns * rows);
+                assert!(is_permutation(&ranks), "{:?} {}x{}", pattern, columns, rows);
+            }
+        }
+        assert_eq!(RevealPattern::ColumnMajor.ranks(5, 1), vec![0, 1, 2, 3, 4]);
+        assert_eq!(RevealPattern::Diagonal.ranks(1, 5), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn ranks_of_an_empty_grid() {
+        for pattern in PATTERNS {
+            assert!(pattern.ranks(0, 0).is_empty());
+            assert!(pattern.ranks(0, 3).is_empty());
+            assert!(pattern.ranks(3, 0).is_empty());
+        }
+    }
+
+    #[test]
+    fn spiral() {
+        assert_eq!(RevealPattern::spiral(3, 3), vec![0, 1, 2, 5, 8, 7, 6, 3, 4]);
+        assert_eq!(RevealPattern::spiral(4, 2), vec![0, 1, 2, 3, 7, 6, 5, 4]);
+        assert_eq!(RevealPattern::spiral(1, 3), vec![0, 1, 2]);
+        assert_eq!(RevealPattern::spiral(3, 1), vec![0, 1, 2]);
+        assert_eq!(RevealPattern::spiral(1, 1), vec![0]);
+        assert!(RevealPattern::spiral(0, 3).is_empty());
+        assert!(RevealPattern::spiral(3, 0).is_empty());
+    }
+
+    #[test]
+    fn easing_starts_and_ends_with_the_animation() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            // clamped outside of the duration
+            assert_eq!(easing.apply(-0.5), 0.0);
+            assert_eq!(easing.apply(1.5), 1.0);
+            // never goes back
+            let steps = (0..=20)
+                .map(|i| easing.apply(i as f64 / 20.0))
+                .collect::<Vec<_>>();
+            assert!(steps.windows(2).all(|w| w[0] <= w[1]), "{:?}", easing);
+        }
+    }
+
+    #[test]
+    fn easing_curves() {
+        assert_eq!(Easing::Linear.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.125);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.875);
+        assert_eq!(Easing::EaseInOut.apply(0.25), 0.0625);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::custom_element::AttributeValues;
+use std::str::FromStr;
+
+/// What starts the reveal animation of the dice
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiceTrigger {
+    /// each time the dice is clicked, this is the default
+    Click,
+    /// as soon as the content of the dice is mounted
+    Mount,
+    /// when the dice scrolls into view
+    Visible,
+    /// only when `Msg::AnimateIn` is sent to the dice
+    Manual,
+}
+
+/// The state of the content of the dice
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiceState {
+    Hidden,
+    /// the slices are being revealed
+    Revealing,
+    Revealed,
+    /// the slices are being removed
+    Hiding,
+}
+
+impl Default for DiceTrigger {
+    fn default() -> Self {
+        DiceTrigger::Click
+    }
+}
+
+impl DiceState {
+    pub fn is_animating(&self) -> bool {
+        matches!(self, DiceState::Revealing | DiceState::Hiding)
+    }
+}
+
+impl FromStr for DiceTrigger {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "click" => Ok(DiceTrigger::Click),
+            "mount" => Ok(DiceTrigger::Mount),
+            "visible" => Ok(DiceTrigger::Visible),
+            "manual" => Ok(DiceTrigger::Manual),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for DiceTrigger {
+    const VALUES: &'static [&'static str] = &["click", "mount", "visible", "manual"];
+}
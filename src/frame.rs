@@ -1,19 +1,38 @@
+use crate::custom_element::{
+    self, register_custom_element, AttributeDef, AttributeValues, CustomElement, DeclaredAttributes,
+};
+use crate::observer::{SizeObserver, VisibilityObserver, RESIZE_EVENT, VISIBLE_EVENT};
+use crate::scope::Scope;
 use crate::Status;
 use crate::Theme;
 use async_trait::async_trait;
 use css_colors::Color;
+use sauron::wasm_bindgen::JsCast;
 use sauron::{
     dom::{Callback, WebComponent},
     html::{attributes::*, events::*, *},
+    svg::attributes::{points, preserve_aspect_ratio, view_box, xmlns},
     *,
 };
 use std::collections::BTreeMap;
 use std::str::FromStr;
-use web_sys::MouseEvent;
+use web_sys::{KeyboardEvent, MouseEvent};
+
+pub use assembly::{Assembly, AssemblyState, AssemblyTrigger};
+pub use background::Background;
+pub use corner::{CornerStyle, CornerStyles};
+pub use grid::FrameGrid;
+pub use shape::{Corners, Shape, Sides};
 
-const COMPONENT_NAME: &str = "sfui-frame";
-const DEFAULT_WIDTH: usize = 100;
-const DEFAULT_HEIGHT: usize = 40;
+mod assembly;
+mod background;
+mod corner;
+mod grid;
+mod shape;
+
+pub(crate) const COMPONENT_NAME: &str = "sfui-frame";
+/// the keyframes of the blinking corner markers, shared by all the frames
+const CORNER_BLINK_ANIMATION: &str = "sfui-frame-corner-blink";
 
 #[derive(Clone, Debug)]
 pub enum Msg<XMSG> {
@@ -23,6 +42,22 @@ pub enum Msg<XMSG> {
     HighlightEnd,
     External(XMSG),
     ContentTargetMounted(MountEvent),
+    /// the title label is mounted
+    TitleMounted(MountEvent),
+    /// the outermost element of the frame is mounted
+    FrameMounted(MountEvent),
+    /// the frame is resized
+    Resized,
+    /// the frame scrolled into view
+    Visible,
+    /// play the assembly animation of the frame
+    Assemble,
+    /// play the assembly animation of the frame in reverse
+    Disassemble,
+    /// the assembly or disassembly animation has completed
+    AssemblyEnd,
+    /// a key is pressed while the interactive frame has the focus
+    KeyDown(KeyboardEvent),
 }
 
 #[derive(Debug)]
@@ -31,19 +66,85 @@ pub struct Frame<XMSG> {
     clicked: bool,
     hovered: bool,
     click_listeners: Vec<Callback<MouseEvent, XMSG>>,
+    /// called when the mouse enters and leaves the frame
+    hover_listeners: Vec<Callback<bool, XMSG>>,
     /// called when the container for the content is mounted
     container_mounted_listeners: Vec<Callback<MountEvent, XMSG>>,
-    width: Option<usize>,
-    height: Option<usize>,
+    /// called with the measured `(width, height)` of the frame whenever it is resized
+    resize_listeners: Vec<Callback<(f32, f32), XMSG>>,
+    /// explicit size of the frame, otherwise the frame is sized by its content
+    width: Option<f32>,
+    height: Option<f32>,
+    min_width: Option<f32>,
+    max_width: Option<f32>,
+    min_height: Option<f32>,
+    max_height: Option<f32>,
     theme: Theme,
     /// the status of the button which changes the color pallet of the button
     status: Option<Status>,
     children: Vec<Node<XMSG>>,
     content_target_node: Option<web_sys::Node>,
+    /// the outermost element of the frame, which the svg outline is stretched to
+    frame_node: Option<web_sys::Node>,
     dimension: Dimension,
+    /// the sizes set individually, which are kept when the dimension preset is changed
+    dimension_overrides: DimensionOverrides,
+    /// project the light dom children of the host element using slots,
+    /// enabled when used as `sfui-frame` custom element
+    slotted: bool,
+    /// when set, the outline of the frame is drawn as an svg polygon of this shape
+    /// instead of the border and corner divs
+    shape: Option<Shape>,
+    /// the size of the frame as measured from its outermost element
+    measured_width: Option<f32>,
+    measured_height: Option<f32>,
+    /// keeps track of the size of the frame, once it is mounted
+    size_observer: Option<SizeObserver>,
+    /// the entry and exit animation of the frame, the frame is drawn right away if not set
+    assembly: Option<Assembly>,
+    assembly_state: AssemblyState,
+    /// called with the final state when the assembly or disassembly animation completes
+    assembly_listeners: Vec<Callback<AssemblyState, XMSG>>,
+    /// used when the frame is assembled once it becomes visible
+    visibility_observer: Option<VisibilityObserver>,
+    /// title label which is cut into the top border of the frame
+    title: Option<String>,
+    /// the title label, which is measured to cut a gap for it in the svg outline
+    title_node: Option<web_sys::Node>,
+    /// the horizontal span of the title label measured from the left of the frame
+    title_gap: Option<(f32, f32)>,
+    /// content of the header strip, such as action buttons
+    header: Vec<Node<XMSG>>,
+    /// content of the footer bar
+    footer: Vec<Node<XMSG>>,
+    /// namespace of the class names, so the styles of this frame don't affect other frames
+    scope: Scope,
+    /// how many frames this frame is nested in, the dimension is inset for each level
+    depth: Option<usize>,
+    /// the nesting depth detected from the ancestors of the `sfui-frame` element,
+    /// used when the depth is not set explicitly
+    detected_depth: usize,
+    /// the `sfui-frame` element, which the resize and visible events are dispatched on
+    host: Option<web_sys::Element>,
+    /// the frame glows when hovered, highlights when clicked and can be focused and
+    /// clicked with the keyboard, used for clickable tiles and list items
+    interactive: bool,
+    /// clicking the frame toggles its selected state
+    selectable: bool,
+    selected: bool,
+    /// called with the new selected state when it is toggled by clicking the frame
+    select_listeners: Vec<Callback<bool, XMSG>>,
+    /// the backdrop drawn behind the content
+    background: Option<Background>,
+    /// the opacity of the backdrop, from 0.0 to 1.0
+    background_opacity: f32,
+    /// the decoration of each of the corners
+    corner_styles: CornerStyles,
 }
 
-#[derive(Debug)]
+/// The look of the frame, use one of the presets or parse it from the `feature` attribute:
+/// `tight`, `static`, `hud`, `minimal` or `bracket-only`
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Feature {
     /// has corners
     pub has_corners: bool,
@@ -61,7 +162,9 @@ pub struct Feature {
     pub has_border_box_shadow: bool,
 }
 
-#[derive(Debug, Clone)]
+/// The sizes of the corners and padding of the frame, use one of the presets
+/// or parse it from the `dimension` attribute: `small`, `large` or `static`
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Dimension {
     /// width of the corner clip of this button
     pub corner_width: i32,
@@ -79,8 +182,26 @@ impl Default for Dimension {
     }
 }
 
+impl FromStr for Dimension {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "small" => Ok(Dimension::small()),
+            "large" => Ok(Dimension::large()),
+            "static" => Ok(Dimension::static_frame()),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for Dimension {
+    const VALUES: &'static [&'static str] = &["small", "large", "static"];
+}
+
 impl Dimension {
-    fn large() -> Self {
+    /// thick and long corners, which expands further when hovered
+    pub fn large() -> Self {
         Self {
             corner_width: 4,
             corner_length: 16,
@@ -88,6 +209,8 @@ impl Dimension {
             content_padding: 0,
         }
     }
+
+    /// thin and short corners, used in buttons
     pub fn small() -> Self {
         Self {
             corner_width: 2,
@@ -97,6 +220,7 @@ impl Dimension {
         }
     }
 
+    /// thin and short corners with padding around the content, this is the default
     pub fn static_frame() -> Self {
         Self {
             corner_width: 2,
@@ -105,6 +229,38 @@ impl Dimension {
             content_padding: 10,
         }
     }
+
+    /// the dimension of a frame nested `depth` levels deep inside other frames.
+    /// The corners and padding gets smaller on each level, so the corners of the nested
+    /// frame don't run into the corners of the outer frames.
+    pub fn inset(&self, depth: usize) -> Self {
+        let depth = depth as i32;
+        Self {
+            corner_width: (self.corner_width - depth).max(self.corner_width.min(1)),
+            corner_length: (self.corner_length - depth * 2).max(self.corner_length.min(4)),
+            corner_expand_distance: self.corner_expand_distance / (depth + 1),
+            content_padding: (self.content_padding - depth * 4).max(0),
+        }
+    }
+}
+
+/// The sizes of the [`Dimension`] which are set on their own, on top of the preset
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct DimensionOverrides {
+    corner_width: Option<i32>,
+    corner_length: Option<i32>,
+    content_padding: Option<i32>,
+}
+
+impl DimensionOverrides {
+    fn apply(&self, dimension: Dimension) -> Dimension {
+        Dimension {
+            corner_width: self.corner_width.unwrap_or(dimension.corner_width),
+            corner_length: self.corner_length.unwrap_or(dimension.corner_length),
+            content_padding: self.content_padding.unwrap_or(dimension.content_padding),
+            ..dimension
+        }
+    }
 }
 
 impl<XMSG> Default for Frame<XMSG> {
@@ -114,14 +270,47 @@ impl<XMSG> Default for Frame<XMSG> {
             clicked: false,
             hovered: false,
             click_listeners: vec![],
+            hover_listeners: vec![],
             container_mounted_listeners: vec![],
+            resize_listeners: vec![],
             width: None,
             height: None,
+            min_width: None,
+            max_width: None,
+            min_height: None,
+            max_height: None,
             theme: Theme::default(),
             status: None,
             children: vec![],
             content_target_node: None,
+            frame_node: None,
             dimension: Dimension::default(),
+            dimension_overrides: DimensionOverrides::default(),
+            slotted: false,
+            shape: None,
+            measured_width: None,
+            measured_height: None,
+            size_observer: None,
+            assembly: None,
+            assembly_state: AssemblyState::Assembled,
+            assembly_listeners: vec![],
+            visibility_observer: None,
+            title: None,
+            title_node: None,
+            title_gap: None,
+            header: vec![],
+            footer: vec![],
+            scope: Scope::new(COMPONENT_NAME),
+            depth: None,
+            detected_depth: 0,
+            host: None,
+            interactive: false,
+            selectable: false,
+            selected: false,
+            select_listeners: vec![],
+            background: None,
+            background_opacity: 0.5,
+            corner_styles: CornerStyles::default(),
         }
     }
 }
@@ -138,24 +327,241 @@ impl<XMSG> Frame<XMSG> {
     pub fn set_status(&mut self, status: Status) {
         self.status = Some(status);
     }
-    fn computed_width(&self) -> usize {
-        // use the supplied width if it is specified
-        if let Some(width) = self.width {
-            width
-        } else {
-            DEFAULT_WIDTH
-        }
+
+    pub fn clear_status(&mut self) {
+        self.status = None;
     }
 
+    /// the sizes of the corners and padding, the sizes which are set on their own
+    /// with [`Frame::set_corner_width`], [`Frame::set_corner_length`]
+    /// and [`Frame::set_padding`] take precedence over the preset
     pub fn set_dimension(&mut self, dimension: Dimension) {
         self.dimension = dimension;
     }
 
-    fn computed_height(&self) -> usize {
-        if let Some(height) = self.height {
-            height
+    pub fn set_corner_width(&mut self, corner_width: i32) {
+        self.dimension_overrides.corner_width = Some(corner_width);
+    }
+
+    pub fn set_corner_length(&mut self, corner_length: i32) {
+        self.dimension_overrides.corner_length = Some(corner_length);
+    }
+
+    /// the padding around the content of the frame
+    pub fn set_padding(&mut self, padding: i32) {
+        self.dimension_overrides.content_padding = Some(padding);
+    }
+
+    /// make the frame respond to hover, click and keyboard
+    pub fn set_interactive(&mut self, interactive: bool) {
+        self.interactive = interactive;
+    }
+
+    /// clicking the frame toggles its selected state, this also makes the frame interactive
+    pub fn set_selectable(&mut self, selectable: bool) {
+        self.selectable = selectable;
+        if selectable {
+            self.interactive = true;
+        }
+    }
+
+    /// use the same decoration on all the corners
+    pub fn set_corner_style(&mut self, style: CornerStyle) {
+        self.corner_styles = CornerStyles::all(style);
+    }
+
+    /// the decoration of each of the corners
+    pub fn set_corner_styles(&mut self, styles: CornerStyles) {
+        self.corner_styles = styles;
+    }
+
+    /// draw a backdrop behind the content of the frame
+    pub fn set_background(&mut self, background: Background) {
+        self.background = Some(background);
+    }
+
+    pub fn clear_background(&mut self) {
+        self.background = None;
+    }
+
+    /// the opacity of the backdrop, from 0.0 to 1.0
+    pub fn set_background_opacity(&mut self, opacity: f32) {
+        self.background_opacity = opacity.clamp(0.0, 1.0);
+    }
+
+    pub fn set_selected(&mut self, selected: bool) {
+        self.selected = selected;
+    }
+
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    /// tell the frame how many frames it is nested in, a frame used as `sfui-frame`
+    /// detects this from its ancestor elements unless the `depth` attribute is set
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = Some(depth);
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth.unwrap_or(self.detected_depth)
+    }
+
+    /// the dimension inset for the nesting depth of this frame
+    fn inset_dimension(&self) -> Dimension {
+        self.dimension_overrides
+            .apply(self.dimension)
+            .inset(self.depth())
+    }
+
+    /// draw the outline of the frame as an svg polygon with this shape
+    pub fn set_shape(&mut self, shape: Shape) {
+        self.shape = Some(shape);
+    }
+
+    /// explicit width of the frame, otherwise the frame is as wide as its content
+    pub fn set_width(&mut self, width: f32) {
+        self.width = Some(width);
+    }
+
+    /// explicit height of the frame, otherwise the frame is as tall as its content
+    pub fn set_height(&mut self, height: f32) {
+        self.height = Some(height);
+    }
+
+    pub fn set_min_width(&mut self, min_width: f32) {
+        self.min_width = Some(min_width);
+    }
+
+    pub fn set_max_width(&mut self, max_width: f32) {
+        self.max_width = Some(max_width);
+    }
+
+    pub fn set_min_height(&mut self, min_height: f32) {
+        self.min_height = Some(min_height);
+    }
+
+    pub fn set_max_height(&mut self, max_height: f32) {
+        self.max_height = Some(max_height);
+    }
+
+    /// set the title of the frame, which is cut into the top border
+    pub fn set_title(&mut self, title: &str) {
+        self.title = Some(title.to_string());
+    }
+
+    /// remove the title of the frame, the top border is drawn in full
+    pub fn clear_title(&mut self) {
+        self.title = None;
+        self.title_node = None;
+        self.title_gap = None;
+    }
+
+    /// set the content of the header strip, which is displayed at the top of the frame
+    pub fn set_header(&mut self, header: impl IntoIterator<Item = Node<XMSG>>) {
+        self.header = header.into_iter().collect();
+    }
+
+    /// set the content of the footer bar, which is displayed at the bottom of the frame
+    pub fn set_footer(&mut self, footer: impl IntoIterator<Item = Node<XMSG>>) {
+        self.footer = footer.into_iter().collect();
+    }
+
+    /// animate the frame in and out using `assembly`,
+    /// the frame is hidden until the assembly is triggered.
+    /// A frame which is already mounted or animated keeps its current state
+    pub fn set_assembly(&mut self, assembly: Assembly) {
+        if self.assembly.is_none() && self.content_target_node.is_none() {
+            self.assembly_state = AssemblyState::Disassembled;
+        }
+        self.assembly = Some(assembly);
+    }
+
+    /// change what starts the assembly, keeping the rest of the assembly
+    pub fn set_assembly_trigger(&mut self, trigger: AssemblyTrigger) {
+        let assembly = self.assembly.unwrap_or_default();
+        self.set_assembly(Assembly {
+            trigger,
+            ..assembly
+        });
+    }
+
+    /// change the duration of each phase of the assembly, keeping the rest of the assembly
+    pub fn set_assembly_duration(&mut self, duration_ms: usize) {
+        let assembly = self.assembly.unwrap_or_default();
+        self.set_assembly(Assembly {
+            duration_ms,
+            ..assembly
+        });
+    }
+
+    /// change the delay between the phases of the assembly, keeping the rest of the assembly
+    pub fn set_assembly_stagger(&mut self, stagger_ms: usize) {
+        let assembly = self.assembly.unwrap_or_default();
+        self.set_assembly(Assembly {
+            stagger_ms,
+            ..assembly
+        });
+    }
+
+    /// assemble or disassemble the frame,
+    /// a frame without an assembly is given a manual one to be assembled
+    pub fn set_assembled(&mut self, assembled: bool) {
+        if assembled {
+            if self.assembly.is_none() {
+                self.set_assembly(Assembly::manual());
+            }
+            self.assemble();
         } else {
-            DEFAULT_HEIGHT
+            self.disassemble();
+        }
+    }
+
+    pub fn assembly_state(&self) -> AssemblyState {
+        self.assembly_state
+    }
+
+    /// play the assembly animation, if the frame is not yet assembled
+    pub fn assemble(&mut self) {
+        if self.assembly.is_some() && !self.assembly_state.is_assembled() {
+            self.assembly_state = AssemblyState::Assembling;
+        }
+    }
+
+    /// play the assembly animation in reverse, if the frame is assembled
+    pub fn disassemble(&mut self) {
+        if self.assembly.is_some() && self.assembly_state.is_assembled() {
+            self.assembly_state = AssemblyState::Disassembling;
+        }
+    }
+
+    /// the `(width, height)` of the frame including the title bar, header and footer,
+    /// this is only available after the frame is mounted
+    pub fn measured_size(&self) -> Option<(f32, f32)> {
+        self.measured_width.zip(self.measured_height)
+    }
+
+    /// measure the layout size of the whole frame, which is not affected by transforms
+    fn measure_frame(&mut self) {
+        if let Some(frame_node) = &self.frame_node {
+            let frame_element: &web_sys::HtmlElement = frame_node.unchecked_ref();
+            let width = frame_element.offset_width() as f32;
+            self.measured_width = Some(width);
+            self.measured_height = Some(frame_element.offset_height() as f32);
+            self.title_gap = self.title_node.as_ref().map(|title_node| {
+                let title_element: &web_sys::Element = title_node.unchecked_ref();
+                let frame_rect = frame_element.get_bounding_client_rect();
+                let title_rect = title_element.get_bounding_client_rect();
+                // the bounding rects are scaled by the transforms of the frame
+                let scale = if frame_rect.width() > 0.0 {
+                    width as f64 / frame_rect.width()
+                } else {
+                    1.0
+                };
+                let left = (title_rect.left() - frame_rect.left()) * scale;
+                let right = (title_rect.right() - frame_rect.left()) * scale;
+                (left as f32, right as f32)
+            });
         }
     }
 
@@ -163,41 +569,152 @@ impl<XMSG> Frame<XMSG> {
         node_list([
             view_if(
                 self.feature.has_borders,
-                div([class("border border-left")], []),
+                div([class(self.scope.class("border border-left"))], []),
             ),
             view_if(
                 self.feature.has_borders,
-                div([class("border border-right")], []),
+                div([class(self.scope.class("border border-right"))], []),
             ),
+            // the title bar takes the place of the top border
             view_if(
-                self.feature.has_borders,
-                div([class("border border-top")], []),
+                self.feature.has_borders && self.title.is_none(),
+                div([class(self.scope.class("border border-top"))], []),
             ),
             view_if(
                 self.feature.has_borders,
-                div([class("border border-bottom")], []),
+                div([class(self.scope.class("border border-bottom"))], []),
             ),
         ])
     }
 
-    fn view_corners(&self) -> Node<Msg<XMSG>> {
-        node_list([
-            view_if(
-                self.feature.has_corners,
-                div([class("corner corner__top-left")], []),
-            ),
-            view_if(
-                self.feature.has_corners,
-                div([class("corner corner__bottom-left")], []),
-            ),
-            view_if(
-                self.feature.has_corners,
-                div([class("corner corner__top-right")], []),
+    /// the svg outline of the frame, stretched to the measured size of the frame.
+    /// The stroke uses `non-scaling-stroke` so it stays crisp while the frame is resized.
+    fn view_shape(&self, shape: &Shape) -> Node<Msg<XMSG>> {
+        // the outline can only be drawn once the size of the frame is known
+        let Some((width, height)) = self.measured_size() else {
+            return node_list([]);
+        };
+        svg(
+            [
+                xmlns("http://www.w3.org/2000/svg"),
+                preserve_aspect_ratio("none"),
+                class(self.scope.class("shape_svg")),
+                view_box([0.0, 0.0, width, height]),
+            ],
+            [
+                if let Some(gap) = self.title_gap {
+                    // the outline is left open where the title is cut into the top side
+                    polyline(
+                        [
+                            class(self.scope.class("shape_polygon")),
+                            attr("pathLength", 1),
+                            points(shape::points_str(
+                                &shape.points_with_gap(width, height, gap),
+                            )),
+                        ],
+                        [],
+                    )
+                } else {
+                    polygon(
+                        [
+                            class(self.scope.class("shape_polygon")),
+                            // normalized length, used for the draw-in animation of the outline
+                            attr("pathLength", 1),
+                            points(shape::points_str(&shape.points(width, height))),
+                        ],
+                        [],
+                    )
+                },
+                view_if(
+                    shape.beveled,
+                    polygon(
+                        [
+                            class(self.scope.class("shape_bevel")),
+                            points(shape::points_str(&shape.bevel_points(width, height))),
+                        ],
+                        [],
+                    ),
+                ),
+            ],
+        )
+    }
+
+    /// the title label on the top border, the border is split into two lines
+    /// leaving a gap for the title text
+    fn view_title_bar(&self) -> Node<Msg<XMSG>> {
+        if let Some(title) = &self.title {
+            div(
+                [class(self.scope.class("title_bar"))],
+                [
+                    // the svg outline leaves its own gap for the title
+                    view_if(
+                        self.feature.has_borders && self.shape.is_none(),
+                        div([class(self.scope.class("border title_lead"))], []),
+                    ),
+                    span(
+                        [
+                            class(self.scope.class("title")),
+                            on_mount(|me| Msg::TitleMounted(me)),
+                        ],
+                        [if self.slotted {
+                            // the title text is the fallback of the `title` slot
+                            node! { <slot name="title">{text(title)}</slot> }
+                        } else {
+                            text(title)
+                        }],
+                    ),
+                    view_if(
+                        self.feature.has_borders && self.shape.is_none(),
+                        div([class(self.scope.class("border title_rest"))], []),
+                    ),
+                ],
+            )
+        } else {
+            node_list([])
+        }
+    }
+
+    /// a region of the frame with the supplied content,
+    /// followed by the named slot when used as a custom element
+    fn view_region(
+        &self,
+        name: &'static str,
+        content: &[Node<XMSG>],
+        always: bool,
+    ) -> Node<Msg<XMSG>> {
+        view_if(
+            always || self.slotted || !content.is_empty(),
+            div(
+                [class(self.scope.class(name))],
+                content
+                    .iter()
+                    .cloned()
+                    .map(|node| node.map_msg(|xmsg| Msg::External(xmsg)))
+                    .chain(self.slotted.then(|| node! { <slot name=name></slot> })),
             ),
+        )
+    }
+
+    fn view_corners(&self) -> Node<Msg<XMSG>> {
+        let styles = &self.corner_styles;
+        let corner = |position: &str, style: &CornerStyle| {
             view_if(
                 self.feature.has_corners,
-                div([class("corner corner__bottom-right")], []),
-            ),
+                div(
+                    [class(self.scope.class(&format!(
+                        "corner corner__{} {}",
+                        position,
+                        style.class_name()
+                    )))],
+                    [],
+                ),
+            )
+        };
+        node_list([
+            corner("top-left", &styles.top_left),
+            corner("bottom-left", &styles.bottom_left),
+            corner("top-right", &styles.top_right),
+            corner("bottom-right", &styles.bottom_right),
         ])
     }
 }
@@ -217,16 +734,47 @@ where
                 let pmsg_list = self
                     .click_listeners
                     .iter()
-                    .map(|listener| listener.emit(mouse_event.clone()));
-                Effects::with_external(pmsg_list)
+                    .map(|listener| listener.emit(mouse_event.clone()))
+                    .collect::<Vec<_>>();
+                let selected = if self.selectable {
+                    self.selected = !self.selected;
+                    self.select_listeners
+                        .iter()
+                        .map(|listener| listener.emit(self.selected))
+                        .collect()
+                } else {
+                    vec![]
+                };
+                Effects::with_external(pmsg_list.into_iter().chain(selected))
+            }
+            Msg::KeyDown(key_event) => {
+                // activate the frame with the keyboard the same way as a button does,
+                // the dispatched click goes through `Msg::Click`.
+                // Keys pressed in the inputs or the nested frames inside it are left alone
+                let target = key_event.target();
+                let current_target = key_event.current_target();
+                if self.interactive
+                    && target.is_some()
+                    && target == current_target
+                    && matches!(key_event.key().as_str(), "Enter" | " ")
+                {
+                    key_event.prevent_default();
+                    if let Some(target) = current_target {
+                        target.unchecked_into::<web_sys::HtmlElement>().click();
+                    }
+                }
+                Effects::none()
             }
+            // the listeners are only notified when the hovered state changes
             Msg::HoverIn => {
+                let changed = !self.hovered;
                 self.hovered = true;
-                Effects::none()
+                Effects::with_external(self.emit_hover(changed))
             }
             Msg::HoverOut => {
+                let changed = self.hovered;
                 self.hovered = false;
-                Effects::none()
+                Effects::with_external(self.emit_hover(changed))
             }
             Msg::HighlightEnd => {
                 self.clicked = false;
@@ -236,6 +784,17 @@ where
             Msg::ContentTargetMounted(me) => {
                 let mount_event = me.clone();
                 let target_node = me.target_node;
+                let content_element: &web_sys::Element = target_node.unchecked_ref();
+                if let Some(assembly) = &self.assembly {
+                    match assembly.trigger {
+                        AssemblyTrigger::Mount => self.assemble(),
+                        AssemblyTrigger::Visible => {
+                            self.visibility_observer =
+                                Some(VisibilityObserver::observe(content_element));
+                        }
+                        AssemblyTrigger::Manual => (),
+                    }
+                }
                 self.content_target_node = Some(target_node);
                 let external = self
                     .container_mounted_listeners
@@ -243,6 +802,62 @@ where
                     .map(|listener| listener.emit(mount_event.clone()));
                 Effects::with_external(external)
             }
+            Msg::Visible => {
+                self.assemble();
+                if let Some(host) = &self.host {
+                    custom_element::dispatch_host_event(host, VISIBLE_EVENT, &JsValue::NULL);
+                }
+                Effects::none()
+            }
+            Msg::Assemble => {
+                self.assemble();
+                Effects::none()
+            }
+            Msg::Disassemble => {
+                self.disassemble();
+                Effects::none()
+            }
+            Msg::AssemblyEnd => {
+                self.assembly_state = match self.assembly_state {
+                    AssemblyState::Assembling => AssemblyState::Assembled,
+                    AssemblyState::Disassembling => AssemblyState::Disassembled,
+                    state => state,
+                };
+                let state = self.assembly_state;
+                let external = self
+                    .assembly_listeners
+                    .iter()
+                    .map(|listener| listener.emit(state));
+                Effects::with_external(external)
+            }
+            Msg::TitleMounted(me) => {
+                self.title_node = Some(me.target_node);
+                self.measure_frame();
+                Effects::none()
+            }
+            Msg::FrameMounted(me) => {
+                let frame_element: &web_sys::Element = me.target_node.unchecked_ref();
+                self.size_observer = Some(SizeObserver::observe(frame_element));
+                self.frame_node = Some(me.target_node);
+                self.measure_frame();
+                Effects::none()
+            }
+            Msg::Resized => {
+                self.measure_frame();
+                if let Some(size) = self.measured_size() {
+                    if let Some(host) = &self.host {
+                        let detail = custom_element::size_detail(size.0, size.1);
+                        custom_element::dispatch_host_event(host, RESIZE_EVENT, &detail);
+                    }
+                    let external = self
+                        .resize_listeners
+                        .iter()
+                        .map(|listener| listener.emit(size));
+                    Effects::with_external(external)
+                } else {
+                    Effects::none()
+                }
+            }
         }
     }
 
@@ -250,43 +865,139 @@ where
         div(
             [
                 class(COMPONENT_NAME),
+                class(self.scope.class(COMPONENT_NAME)),
+                // exposed out of the shadow root of `sfui-frame`, to override the margin
+                attr("part", "frame"),
                 classes_flag([
-                    ("clicked", self.clicked),
-                    ("expand_corners", self.feature.expand_corners),
-                    ("has_corner_box_shadow", self.feature.has_corner_box_shadow),
-                    ("has_border_box_shadow", self.feature.has_border_box_shadow),
-                    ("hovered", self.hovered),
+                    (self.scope.class("clicked"), self.clicked),
+                    (
+                        self.scope.class("expand_corners"),
+                        self.feature.expand_corners,
+                    ),
+                    (
+                        self.scope.class("has_corner_box_shadow"),
+                        self.feature.has_corner_box_shadow,
+                    ),
+                    (
+                        self.scope.class("has_border_box_shadow"),
+                        self.feature.has_border_box_shadow,
+                    ),
+                    (self.scope.class("hovered"), self.hovered),
+                    (self.scope.class("interactive"), self.interactive),
+                    (self.scope.class("selected"), self.selected),
+                    (
+                        self.scope.class("has_background"),
+                        self.background.is_some(),
+                    ),
+                    (self.scope.class("has_title"), self.title.is_some()),
+                    (self.scope.class("animated"), self.assembly.is_some()),
+                    (
+                        self.scope.class("assembled"),
+                        self.assembly_state.is_assembled(),
+                    ),
+                    (
+                        self.scope.class("assembling"),
+                        self.assembly_state == AssemblyState::Assembling,
+                    ),
+                    (
+                        self.scope.class("disassembling"),
+                        self.assembly_state == AssemblyState::Disassembling,
+                    ),
                 ]),
                 if let Some(ref status) = self.status {
-                    class(status.class_name())
+                    class(self.scope.class(status.class_name()))
                 } else {
                     empty_attr()
                 },
                 // normally click should be attached to the actual button element
                 on_click(Msg::Click),
-                // the mouseover events are attached here since the hover element z-index is
+                // the hover events are attached here since the hover element z-index is
                 // higher than the actual button, which will cause a janky animation
                 // when the mouse is triggering alt hover in and out, since covered by the hover
-                // layer effect
-                on_mouseover(|_| Msg::HoverIn),
-                on_mouseout(|_| Msg::HoverOut),
+                // layer effect. Unlike mouseover and mouseout, mouseenter and mouseleave
+                // don't fire when moving between the children of the frame
+                on_mouseenter(|_| Msg::HoverIn),
+                on_mouseleave(|_| Msg::HoverOut),
+                on_mount(|me| Msg::FrameMounted(me)),
+                on(RESIZE_EVENT, |_| Msg::Resized),
+                // focusable and clickable with the keyboard
+                if self.interactive {
+                    tabindex(0)
+                } else {
+                    empty_attr()
+                },
+                if self.interactive {
+                    on_keydown(Msg::KeyDown)
+                } else {
+                    empty_attr()
+                },
+                // a selectable frame is a toggle button, `option` would need a listbox parent
+                if self.interactive || self.selectable {
+                    attr("role", "button")
+                } else {
+                    empty_attr()
+                },
+                if self.selectable {
+                    attr("aria-pressed", self.selected.to_string())
+                } else {
+                    empty_attr()
+                },
             ],
             [div(
                 [],
                 [
-                    // borders
-                    self.view_borders(),
-                    // corners
-                    self.view_corners(),
+                    view_if(
+                        self.background.is_some(),
+                        div([class(self.scope.class("background"))], []),
+                    ),
+                    view_if(
+                        self.interactive,
+                        div(
+                            [
+                                class(self.scope.class("highlight")),
+                                on_transitionend(|_| Msg::HighlightEnd),
+                            ],
+                            [],
+                        ),
+                    ),
+                    if let Some(shape) = &self.shape {
+                        self.view_shape(shape)
+                    } else {
+                        node_list([
+                            // borders
+                            self.view_borders(),
+                            // corners
+                            self.view_corners(),
+                        ])
+                    },
+                    self.view_title_bar(),
+                    // the header strip is always displayed when the frame has a title,
+                    // to make room for it
+                    self.view_region("header", &self.header, self.title.is_some()),
                     div(
                         [
-                            class("content_wrap"),
+                            class(self.scope.class("content_wrap")),
                             on_mount(|me| Msg::ContentTargetMounted(me)),
+                            on(VISIBLE_EVENT, |_| Msg::Visible),
                         ],
                         content
                             .into_iter()
                             .chain(self.children.clone().into_iter())
-                            .map(|node| node.map_msg(|xmsg| Msg::External(xmsg))),
+                            .map(|node| node.map_msg(|xmsg| Msg::External(xmsg)))
+                            .chain(self.slotted.then(|| node! { <slot></slot> })),
+                    ),
+                    self.view_region("footer", &self.footer, false),
+                    // transitions for the whole duration of the assembly,
+                    // used for notifying when the animation has completed
+                    view_if(
+                        self.assembly.is_some(),
+                        div(
+                            [
+                                class(self.scope.class("assembly_marker")),
+                                on_transitionend(|_| Msg::AssemblyEnd),
+                            ],
+                            [],
+                        ),
                     ),
                 ],
             )],
@@ -304,9 +1015,16 @@ where
         let hover_transition_time = 100; // the transition of the lower highligh of the button when hovering
         let highlight_transition = 50; // the transition time for the highlight color of the button when clicked
 
-        let width = self.computed_width();
-        let height = self.computed_height();
-        let content_padding = self.dimension.content_padding;
+        let dimension = self.inset_dimension();
+        let content_padding = dimension.content_padding;
+        // nested frames only leave enough room for their expanded corners
+        let margin = if self.depth() > 0 {
+            px(dimension.corner_width + dimension.corner_expand_distance)
+        } else {
+            px([10, 10])
+        };
+        // size constraints of the frame, unconstrained sides are sized by the content
+        let size = |v: Option<f32>, default: &str| v.map(|v| px(v)).unwrap_or(default.to_string());
 
         let main = jss! {
             // the ROOT component style
@@ -314,7 +1032,14 @@ where
                 display: "inline-block",
                 padding: px(1),
                 position: "relative",
-                margin: px([10, 10]),
+                margin: margin,
+                box_sizing: "border-box",
+                width: size(self.width, "auto"),
+                height: size(self.height, "auto"),
+                min_width: size(self.min_width, "auto"),
+                max_width: size(self.max_width, "none"),
+                min_height: size(self.min_height, "auto"),
+                max_height: size(self.max_height, "none"),
             },
 
             ".content_wrap": {
@@ -326,7 +1051,19 @@ where
             },
         };
 
-        vec![main, self.border_style(), self.corner_style()]
+        [
+            main,
+            self.border_style(),
+            self.corner_style(),
+            self.corner_decoration_style(),
+            self.shape_style(),
+            self.assembly_style(),
+            self.interactive_style(),
+            self.background_style(),
+        ]
+        .iter()
+        .map(|css| self.scope.style(css))
+        .collect()
     }
 }
 
@@ -334,6 +1071,14 @@ impl<XMSG> Frame<XMSG>
 where
     XMSG: 'static,
 {
+    /// a frame which responds to hover, click and keyboard
+    pub fn interactive() -> Self {
+        Self {
+            interactive: true,
+            ..Default::default()
+        }
+    }
+
     pub fn with_options(mut self, feature: Feature) -> Self {
         self.feature = feature;
         self
@@ -344,6 +1089,31 @@ where
         self
     }
 
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.set_depth(depth);
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.set_title(title);
+        self
+    }
+
+    pub fn with_corner_styles(mut self, styles: CornerStyles) -> Self {
+        self.set_corner_styles(styles);
+        self
+    }
+
+    pub fn with_background(mut self, background: Background) -> Self {
+        self.set_background(background);
+        self
+    }
+
+    pub fn with_shape(mut self, shape: Shape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+
     pub fn add_click_listener<F>(&mut self, f: F)
     where
         F: Fn(MouseEvent) -> XMSG + 'static,
@@ -360,6 +1130,59 @@ where
         self.container_mounted_listeners.push(cb);
     }
 
+    pub fn with_assembly(mut self, assembly: Assembly) -> Self {
+        self.set_assembly(assembly);
+        self
+    }
+
+    /// listen to the completion of the assembly and disassembly animation,
+    /// this is called with either `AssemblyState::Assembled` or `AssemblyState::Disassembled`
+    pub fn add_assembly_listener<F>(&mut self, f: F)
+    where
+        F: Fn(AssemblyState) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.assembly_listeners.push(cb);
+    }
+
+    /// listen to the mouse entering and leaving the frame
+    pub fn add_hover_listener<F>(&mut self, f: F)
+    where
+        F: Fn(bool) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.hover_listeners.push(cb);
+    }
+
+    fn emit_hover(&self, changed: bool) -> Vec<XMSG> {
+        if changed {
+            self.hover_listeners
+                .iter()
+                .map(|listener| listener.emit(self.hovered))
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    /// listen to the selected state of the frame, each time it is toggled by clicking the frame
+    pub fn add_select_listener<F>(&mut self, f: F)
+    where
+        F: Fn(bool) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.select_listeners.push(cb);
+    }
+
+    /// listen to the measured `(width, height)` of the frame each time it is resized
+    pub fn add_resize_listener<F>(&mut self, f: F)
+    where
+        F: Fn((f32, f32)) -> XMSG + 'static,
+    {
+        let cb = Callback::from(f);
+        self.resize_listeners.push(cb);
+    }
+
     ///transition time for most effects on the frame
     fn transition_time_ms(&self) -> usize {
         250
@@ -371,7 +1194,7 @@ where
         let base = &theme.controls;
         let transition_time_ms = self.transition_time_ms(); //transition time for most effects on the button
                                                             //
-        let Dimension { corner_length, .. } = self.dimension;
+        let Dimension { corner_length, .. } = self.inset_dimension();
 
         let border_cut = if self.feature.full_borders {
             0
@@ -439,12 +1262,449 @@ where
                 border_width: px([border_width, 0, 0, 0]),
             },
 
-            ".border-bottom": {
+            // the top border when the frame has a title
+            ".title_bar": {
+                position: "absolute",
+                top: 0,
                 left: percent(50),
-                width: format!("calc({} - {})", percent(100),  px(border_cut)),
-                bottom: 0,
-                transform: format!("translate({}, {})", percent(-50), 0),
-                border_width: px([border_width, 0, 0, 0]),
+                width: format!("calc({} - {})", percent(100), px(border_cut)),
+                transform: format!("translate({}, {})", percent(-50), percent(-50)),
+                display: "flex",
+                align_items: "center",
+                z_index: 1,
+            },
+
+            ".title_bar .border": {
+                position: "relative",
+                border_width: px([border_width, 0, 0, 0]),
+            },
+
+            ".title_lead": {
+                width: px(corner_length),
+            },
+
+            ".title_rest": {
+                flex: 1,
+            },
+
+            ".title": {
+                padding: px([0, 6]),
+                color: base.border_color.clone(),
+                font_size: px(13),
+                letter_spacing: px(1),
+                text_transform: "uppercase",
+                white_space: "nowrap",
+            },
+
+            ".error .title": {
+                color: theme.error().to_css(),
+            },
+
+            ".success .title": {
+                color: theme.success().to_css(),
+            },
+
+            ".info .title": {
+                color: theme.info().to_css(),
+            },
+
+            ".warning .title": {
+                color: theme.warning().to_css(),
+            },
+
+            ".header": {
+                display: "flex",
+                align_items: "center",
+                justify_content: "flex-end",
+                gap: px(8),
+                padding: px([4, 8]),
+            },
+
+            ".has_title .header": {
+                padding_top: px(12),
+            },
+
+            ".footer": {
+                display: "flex",
+                align_items: "center",
+                gap: px(8),
+                padding: px([4, 8]),
+                border_top: format!("{} solid {}", px(border_width), base.corner_shadow.clone()),
+            },
+
+            ".border-bottom": {
+                left: percent(50),
+                width: format!("calc({} - {})", percent(100),  px(border_cut)),
+                bottom: 0,
+                transform: format!("translate({}, {})", percent(-50), 0),
+                border_width: px([border_width, 0, 0, 0]),
+            },
+        }
+    }
+
+    /// the borders extends from the corners, then the corners snaps in,
+    /// then the content fades in. Disassembling goes in reverse order.
+    fn assembly_style(&self) -> String {
+        let Some(assembly) = self.assembly else {
+            return String::new();
+        };
+        let Assembly {
+            duration_ms,
+            stagger_ms,
+            ..
+        } = assembly;
+
+        jss! {
+            ".animated .border": {
+                transition: format!("all {}ms ease-out", duration_ms),
+            },
+
+            ".animated.disassembling .border, .animated.disassembling .shape_polygon": {
+                transition_delay: format!("{}ms", stagger_ms * 2),
+            },
+
+            ".animated .border-top": {
+                transform: format!("translate({}, {}) scaleX(0)", percent(-50), 0),
+                transform_origin: "left",
+            },
+
+            ".animated .border-right": {
+                transform: format!("translate({}, {}) scaleY(0)", 0, percent(-50)),
+                transform_origin: "top",
+            },
+
+            ".animated .border-bottom": {
+                transform: format!("translate({}, {}) scaleX(0)", percent(-50), 0),
+                transform_origin: "right",
+            },
+
+            ".animated .border-left": {
+                transform: format!("translate({}, {}) scaleY(0)", 0, percent(-50)),
+                transform_origin: "bottom",
+            },
+
+            ".animated.assembled .border-top, .animated.assembled .border-bottom": {
+                transform: format!("translate({}, {}) scaleX(1)", percent(-50), 0),
+            },
+
+            ".animated.assembled .border-left, .animated.assembled .border-right": {
+                transform: format!("translate({}, {}) scaleY(1)", 0, percent(-50)),
+            },
+
+            ".animated .shape_polygon": {
+                stroke_dasharray: 1,
+                stroke_dashoffset: 1,
+                transition: format!("stroke-dashoffset {}ms ease-out", duration_ms),
+            },
+
+            ".animated.assembled .shape_polygon": {
+                stroke_dashoffset: 0,
+            },
+
+            ".animated .corner, .animated .shape_bevel": {
+                opacity: 0,
+                transform: "scale(1.5)",
+                transition: format!("all {}ms ease-out", duration_ms),
+            },
+
+            ".animated.assembling .corner, .animated.disassembling .corner, .animated.assembling .shape_bevel, .animated.disassembling .shape_bevel": {
+                transition_delay: format!("{}ms", stagger_ms),
+            },
+
+            ".animated.assembled .corner": {
+                opacity: 1,
+                transform: "scale(1)",
+            },
+
+            ".animated.assembled .shape_bevel": {
+                opacity: 0.6,
+                transform: "scale(1)",
+            },
+
+            ".animated .title_bar": {
+                opacity: 0,
+                transition: format!("opacity {}ms ease-out", duration_ms),
+            },
+
+            ".animated.assembled .title_bar": {
+                opacity: 1,
+            },
+
+            ".animated .content_wrap, .animated .header, .animated .footer": {
+                opacity: 0,
+                transition: format!("opacity {}ms ease-in", duration_ms),
+            },
+
+            ".animated.assembling .content_wrap, .animated.assembling .header, .animated.assembling .footer": {
+                transition_delay: format!("{}ms", stagger_ms * 2),
+            },
+
+            ".animated.assembled .content_wrap, .animated.assembled .header, .animated.assembled .footer": {
+                opacity: 1,
+            },
+
+            ".assembly_marker": {
+                position: "absolute",
+                width: 0,
+                height: 0,
+                opacity: 0,
+                transition: format!("opacity {}ms linear", assembly.total_duration_ms()),
+            },
+
+            ".assembled .assembly_marker": {
+                opacity: 1,
+            },
+        }
+    }
+
+    fn interactive_style(&self) -> String {
+        let theme = &self.theme;
+        let base = &theme.controls;
+        let transition_time_ms = self.transition_time_ms();
+        let highlight_transition = 50; // the transition time for the highlight color of the frame when clicked
+
+        jss! {
+            ".interactive": {
+                cursor: "pointer",
+                outline: "none",
+                transition: format!("box-shadow {}ms ease-in", transition_time_ms),
+            },
+
+            // glow when hovered or focused with the keyboard
+            ".interactive.hovered .border, .interactive:focus-visible .border": {
+                box_shadow: format!("{} {}", px([0, 0, 8]), base.hover_shadow.clone()),
+            },
+
+            ".interactive.hovered .corner, .interactive:focus-visible .corner": {
+                box_shadow: format!("{} {}", px([0, 0, 8]), base.corner_shadow.clone()),
+            },
+
+            ".interactive:focus-visible": {
+                box_shadow: format!("{} {}", px([0, 0, 4]), base.hover_shadow.clone()),
+            },
+
+            // highlight when clicked and fades out shortly
+            ".interactive .highlight": {
+                position: "absolute",
+                left: 0,
+                right: 0,
+                top: 0,
+                bottom: 0,
+                pointer_events: "none",
+                background_color: base.highlight_color.clone(),
+                opacity: 0,
+                transition: format!("all {}ms ease-out", highlight_transition),
+            },
+
+            ".interactive.clicked .highlight": {
+                opacity: 0.4,
+            },
+
+            ".interactive.clicked.error .highlight": {
+                background_color: theme.error().to_css(),
+            },
+
+            ".interactive.clicked.success .highlight": {
+                background_color: theme.success().to_css(),
+            },
+
+            ".interactive.clicked.info .highlight": {
+                background_color: theme.info().to_css(),
+            },
+
+            ".interactive.clicked.warning .highlight": {
+                background_color: theme.warning().to_css(),
+            },
+
+            ".selected .content_wrap": {
+                background_color: base.content_background_color.clone(),
+            },
+
+            ".selected .border, .selected .shape_polygon": {
+                border_color: theme.accent_color.clone(),
+                stroke: theme.accent_color.clone(),
+            },
+
+            ".selected .corner": {
+                border_color: theme.accent_color.clone(),
+                box_shadow: format!("{} {}", px([0, 0, 4]), theme.accent_shadow.clone()),
+            },
+        }
+    }
+
+    /// the alternate decorations of the corners, which override the L-shaped brackets
+    fn corner_decoration_style(&self) -> String {
+        let theme = &self.theme;
+        let color = &theme.controls.corner_color;
+        let Dimension {
+            corner_width,
+            corner_length,
+            ..
+        } = self.inset_dimension();
+        let marker = corner_width * 3;
+        let double_width = (corner_width * 2).max(3);
+        // a line across the corner, perpendicular to the gradient direction
+        let diagonal = |direction: &str| {
+            format!(
+                "linear-gradient({}, transparent calc(50% - {}px), {} calc(50% - {}px), {} calc(50% + {}px), transparent calc(50% + {}px))",
+                direction, corner_width, color, corner_width, color, corner_width, corner_width
+            )
+        };
+
+        let decorations = jss! {
+            ".corner.corner--dot, .corner.corner--blink": {
+                width: px(marker),
+                height: px(marker),
+                border_width: 0,
+                background_color: color.clone(),
+            },
+
+            ".corner.corner--dot": {
+                border_radius: "50%",
+            },
+
+            ".corner.corner--blink": {
+                animation: format!("{} 1s step-end infinite", CORNER_BLINK_ANIMATION),
+            },
+
+            ".corner.corner--triangle": {
+                border_width: 0,
+                background_color: color.clone(),
+            },
+
+            ".corner__top-left.corner--triangle": {
+                clip_path: "polygon(0 0, 100% 0, 0 100%)",
+            },
+
+            ".corner__top-right.corner--triangle": {
+                clip_path: "polygon(0 0, 100% 0, 100% 100%)",
+            },
+
+            ".corner__bottom-right.corner--triangle": {
+                clip_path: "polygon(100% 0, 100% 100%, 0 100%)",
+            },
+
+            ".corner__bottom-left.corner--triangle": {
+                clip_path: "polygon(0 0, 100% 100%, 0 100%)",
+            },
+
+            ".corner.corner--double": {
+                border_style: "double",
+            },
+
+            ".corner__top-left.corner--double": {
+                border_width: px([double_width, 0, 0, double_width]),
+            },
+
+            ".corner__top-right.corner--double": {
+                border_width: px([double_width, double_width, 0, 0]),
+            },
+
+            ".corner__bottom-right.corner--double": {
+                border_width: px([0, double_width, double_width, 0]),
+            },
+
+            ".corner__bottom-left.corner--double": {
+                border_width: px([0, 0, double_width, double_width]),
+            },
+
+            ".corner.corner--diagonal": {
+                width: px(corner_length),
+                height: px(corner_length),
+                border_width: 0,
+            },
+
+            ".corner__top-left.corner--diagonal, .corner__bottom-right.corner--diagonal": {
+                background: diagonal("to bottom right"),
+            },
+
+            ".corner__top-right.corner--diagonal, .corner__bottom-left.corner--diagonal": {
+                background: diagonal("to bottom left"),
+            },
+        };
+
+        let blink = format!(
+            "@keyframes {} {{ 0% {{ opacity: 1; }} 50% {{ opacity: 0.2; }} }}",
+            CORNER_BLINK_ANIMATION
+        );
+        [decorations, blink].concat()
+    }
+
+    fn background_style(&self) -> String {
+        let Some(background) = &self.background else {
+            return String::new();
+        };
+        let (image, size) = background.css(&self.theme);
+
+        jss! {
+            // the backdrop is stacked behind the content of the frame
+            ".has_background": {
+                isolation: "isolate",
+            },
+
+            ".background": {
+                position: "absolute",
+                left: 0,
+                right: 0,
+                top: 0,
+                bottom: 0,
+                z_index: -1,
+                pointer_events: "none",
+                background: image,
+                background_size: size,
+                opacity: self.background_opacity,
+            },
+        }
+    }
+
+    fn shape_style(&self) -> String {
+        let theme = &self.theme;
+        let base = &theme.controls;
+        let transition_time_ms = self.transition_time_ms();
+
+        jss! {
+            ".shape_svg": {
+                position: "absolute",
+                top: 0,
+                left: 0,
+                width: percent(100),
+                height: percent(100),
+                overflow: "visible",
+                pointer_events: "none",
+                z_index: 1,
+            },
+
+            ".shape_polygon, .shape_bevel": {
+                fill: "none",
+                stroke: base.border_color.clone(),
+                stroke_width: px(1),
+                vector_effect: "non-scaling-stroke",
+                transition: format!("all {}ms ease-in", transition_time_ms),
+            },
+
+            ".shape_bevel": {
+                stroke: base.corner_color.clone(),
+                opacity: 0.6,
+            },
+
+            ".has_border_box_shadow .shape_polygon": {
+                filter: format!("drop-shadow({} {})", px([0, 0, 4]), base.border_shadow.clone()),
+            },
+
+            ".error .shape_polygon, .error .shape_bevel": {
+                stroke: theme.error().to_css(),
+            },
+
+            ".success .shape_polygon, .success .shape_bevel": {
+                stroke: theme.success().to_css(),
+            },
+
+            ".info .shape_polygon, .info .shape_bevel": {
+                stroke: theme.info().to_css(),
+            },
+
+            ".warning .shape_polygon, .warning .shape_bevel": {
+                stroke: theme.warning().to_css(),
             },
         }
     }
@@ -460,7 +1720,7 @@ where
             corner_length,
             corner_expand_distance,
             ..
-        } = self.dimension;
+        } = self.inset_dimension();
 
         // arm of top, left bottom, right for the corners
         let arm = if outward_corners {
@@ -566,8 +1826,29 @@ impl Default for Feature {
     }
 }
 
+impl FromStr for Feature {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "tight" => Ok(Feature::tight()),
+            "static" => Ok(Feature::static_frame()),
+            "hud" => Ok(Feature::hud()),
+            "minimal" => Ok(Feature::minimal()),
+            "bracket-only" => Ok(Feature::bracket_only()),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for Feature {
+    const VALUES: &'static [&'static str] = &["tight", "static", "hud", "minimal", "bracket-only"];
+}
+
 impl Feature {
-    fn tight() -> Self {
+    /// full borders with the corners lying outside of it,
+    /// the corners expand when hovered and everything glows
+    pub fn tight() -> Self {
         Self {
             has_corners: true,
             outward_corners: true,
@@ -578,7 +1859,10 @@ impl Feature {
             has_border_box_shadow: true,
         }
     }
-    fn static_frame() -> Self {
+
+    /// corners aligned to the borders which stops short of the corners,
+    /// no hover effect and no glow. This is the default
+    pub fn static_frame() -> Self {
         Self {
             has_corners: true,
             outward_corners: false,
@@ -589,163 +1873,164 @@ impl Feature {
             has_border_box_shadow: false,
         }
     }
-}
 
-impl<XMSG> WebComponent<Msg<XMSG>> for Frame<XMSG> {
-    /// what attributes this component is interested in
-    fn observed_attributes() -> Vec<&'static str> {
-        vec!["theme-primary", "theme-background", "feature", "status"]
+    /// heads-up display look: glowing outward corners which expand when hovered,
+    /// with borders that stops short of the corners
+    pub fn hud() -> Self {
+        Self {
+            has_corners: true,
+            outward_corners: true,
+            has_borders: true,
+            full_borders: false,
+            expand_corners: true,
+            has_corner_box_shadow: true,
+            has_border_box_shadow: true,
+        }
     }
 
-    /// called when any of the attributes in observed_attributes is changed
-    fn attribute_changed(
-        program: Program<Self, Msg<XMSG>>,
-        attr_name: &str,
-        _old_value: Option<String>,
-        new_value: Option<String>,
-    ) {
-        let mut app = program.app_mut();
-        match attr_name {
-            "theme-primary" => {
-                if let Some(primary) = new_value {
-                    let background = &app.theme.background_color;
-                    app.theme =
-                        Theme::from_str(&primary, background).expect("must be a valid theme");
-                }
-            }
-            "theme-background" => {
-                if let Some(background) = new_value {
-                    let primary = &app.theme.primary_color;
-                    app.theme =
-                        Theme::from_str(primary, &background).expect("must be a valid theme");
-                }
-            }
-            "status" => {
-                if let Some(v) = new_value {
-                    app.status = Status::from_str(&v).ok();
-                }
-            }
-            _ => (),
+    /// just a thin full border, no corners and no glow
+    pub fn minimal() -> Self {
+        Self {
+            has_corners: false,
+            outward_corners: false,
+            has_borders: true,
+            full_borders: true,
+            expand_corners: false,
+            has_corner_box_shadow: false,
+            has_border_box_shadow: false,
         }
     }
 
-    fn connected_callback(&mut self) {}
-    fn disconnected_callback(&mut self) {}
-    fn adopted_callback(&mut self) {}
-}
-
-#[wasm_bindgen]
-pub struct FrameCustomElement {
-    program: Program<Frame<()>, Msg<()>>,
-    children: Vec<web_sys::Node>,
-}
-
-#[wasm_bindgen]
-impl FrameCustomElement {
-    #[wasm_bindgen(constructor)]
-    pub fn new(node: JsValue) -> Self {
-        use sauron::wasm_bindgen::JsCast;
-
-        let mount_node: &web_sys::Node = node.unchecked_ref();
-        let children = Self::extract_children_nodes(mount_node);
+    /// only the corner brackets, without borders
+    pub fn bracket_only() -> Self {
         Self {
-            program: Program::new(
-                Frame::<()>::default(),
-                mount_node,
-                MountAction::Append,
-                MountTarget::ShadowRoot,
-            ),
-            children,
+            has_corners: true,
+            outward_corners: false,
+            has_borders: false,
+            full_borders: false,
+            expand_corners: false,
+            has_corner_box_shadow: false,
+            has_border_box_shadow: false,
         }
     }
+}
 
-    fn extract_children_nodes(node: &web_sys::Node) -> Vec<web_sys::Node> {
-        let node_list = node.child_nodes();
-        let children_len = node_list.length() as usize;
-        (0..children_len)
-            .into_iter()
-            .map(|i| node_list.item(i as u32).expect("must have an item"))
-            .collect()
+impl<XMSG> DeclaredAttributes for Frame<XMSG>
+where
+    XMSG: 'static,
+{
+    fn attributes() -> Vec<AttributeDef<Self>> {
+        vec![
+            AttributeDef::theme_primary(|app: &Self| app.theme.clone(), Self::set_theme),
+            AttributeDef::theme_background(|app: &Self| app.theme.clone(), Self::set_theme),
+            AttributeDef::enumerated("feature", Self::set_feature),
+            AttributeDef::clearable_enumerated("status", Self::set_status, Self::clear_status),
+            AttributeDef::enumerated("dimension", Self::set_dimension),
+            AttributeDef::enumerated("shape", Self::set_shape),
+            // not `title`, which is the native tooltip of the element
+            AttributeDef::clearable(
+                "heading",
+                |app: &mut Self, title: String| app.set_title(&title),
+                Self::clear_title,
+            ),
+            AttributeDef::enumerated("assembly", Self::set_assembly_trigger),
+            AttributeDef::number("assembly-duration", Self::set_assembly_duration),
+            AttributeDef::number("assembly-stagger", Self::set_assembly_stagger),
+            // assemble or disassemble the frame programmatically
+            AttributeDef::flag("assembled", Self::set_assembled),
+            AttributeDef::number("width", Self::set_width),
+            AttributeDef::number("height", Self::set_height),
+            AttributeDef::number("min-width", Self::set_min_width),
+            AttributeDef::number("max-width", Self::set_max_width),
+            AttributeDef::number("min-height", Self::set_min_height),
+            AttributeDef::number("max-height", Self::set_max_height),
+            AttributeDef::number("corner-length", Self::set_corner_length),
+            AttributeDef::number("corner-width", Self::set_corner_width),
+            AttributeDef::number("padding", Self::set_padding),
+            AttributeDef::number("depth", Self::set_depth),
+            AttributeDef::flag("interactive", Self::set_interactive),
+            AttributeDef::flag("selectable", Self::set_selectable),
+            AttributeDef::flag("selected", Self::set_selected),
+            AttributeDef::clearable_enumerated(
+                "background",
+                Self::set_background,
+                Self::clear_background,
+            ),
+            AttributeDef::enumerated("corner-style", Self::set_corner_style),
+            AttributeDef::enumerated("corner-top-left", |app: &mut Self, style: CornerStyle| {
+                app.corner_styles.top_left = style;
+            }),
+            AttributeDef::enumerated("corner-top-right", |app: &mut Self, style: CornerStyle| {
+                app.corner_styles.top_right = style;
+            }),
+            AttributeDef::enumerated(
+                "corner-bottom-right",
+                |app: &mut Self, style: CornerStyle| {
+                    app.corner_styles.bottom_right = style;
+                },
+            ),
+            AttributeDef::enumerated(
+                "corner-bottom-left",
+                |app: &mut Self, style: CornerStyle| {
+                    app.corner_styles.bottom_left = style;
+                },
+            ),
+            AttributeDef::number("background-opacity", Self::set_background_opacity),
+        ]
     }
+}
 
-    #[wasm_bindgen(getter, static_method_of = Self, js_name = observedAttributes)]
-    pub fn observed_attributes() -> JsValue {
-        let attributes = Frame::<Msg<()>>::observed_attributes();
-        serde_wasm_bindgen::to_value(&attributes).expect("must be serde")
+impl<XMSG> WebComponent<Msg<XMSG>> for Frame<XMSG>
+where
+    XMSG: 'static,
+{
+    /// what attributes this component is interested in
+    fn observed_attributes() -> Vec<&'static str> {
+        Self::attribute_names()
     }
 
-    #[wasm_bindgen(method, js_name = attributeChangedCallback)]
-    pub fn attribute_changed_callback(
-        &self,
+    /// called when any of the attributes in observed_attributes is changed
+    fn attribute_changed(
+        program: Program<Self, Msg<XMSG>>,
         attr_name: &str,
-        old_value: JsValue,
-        new_value: JsValue,
+        _old_value: Option<String>,
+        new_value: Option<String>,
     ) {
-        Frame::<()>::attribute_changed(
-            self.program.clone(),
-            attr_name,
-            old_value.as_string(),
-            new_value.as_string(),
-        );
+        program.app_mut().apply_attribute(attr_name, new_value);
     }
 
-    #[wasm_bindgen(method, js_name = connectedCallback)]
-    pub fn connected_callback(&mut self) {
-        self.program.mount();
-
-        let static_style = <Frame<()> as Application<Msg<()>>>::stylesheet().join("");
-        self.program.inject_style_to_mount(&static_style);
-        let dynamic_style =
-            <Frame<()> as Application<Msg<()>>>::style(&self.program.app()).join("");
-        self.program.inject_style_to_mount(&dynamic_style);
-
-        let children: Vec<web_sys::Node> = self.children.clone();
-        self.program
-            .app_mut()
-            .container_mounted_listener(move |me| {
-                let container_node = &me.target_node;
-                for child in children.iter() {
-                    container_node
-                        .append_child(child)
-                        .expect("must append child..");
-                }
-            });
-    }
+    fn connected_callback(&mut self) {}
 
-    #[wasm_bindgen(method, js_name = disconnectedCallback)]
-    pub fn disconnected_callback(&mut self) {
-        self.program.app_mut().disconnected_callback()
+    /// the observers are created again when the element is mounted on reconnect
+    fn disconnected_callback(&mut self) {
+        self.visibility_observer = None;
+        self.size_observer = None;
     }
+    fn adopted_callback(&mut self) {}
+}
 
-    #[wasm_bindgen(method, js_name = adoptedCallback)]
-    pub fn adopted_callback(&mut self) {
-        self.program.app_mut().adopted_callback()
+impl CustomElement<Msg<()>> for Frame<()> {
+    fn use_slots(&mut self) {
+        self.slotted = true;
     }
 
-    #[wasm_bindgen(method, js_name = appendChild)]
-    pub fn append_child(&mut self, child: JsValue) {
-        use sauron::wasm_bindgen::JsCast;
-
-        let child_node: web_sys::Node = child.unchecked_into();
-        let html_child: &web_sys::HtmlElement = child_node.unchecked_ref();
-        self.children.push(child_node);
+    fn host_connected(&mut self, host: &web_sys::Element) {
+        self.host = Some(host.clone());
+        self.detected_depth = nesting_depth(host);
     }
+}
 
-    pub fn register() {
-        let constructor: Closure<dyn FnMut(JsValue)> = Closure::new(|node: JsValue| {
-            let new: Closure<dyn FnMut(JsValue) -> Self> =
-                Closure::new(|node: JsValue| Self::new(node));
-            js_sys::Reflect::set(&node, &JsValue::from_str("new"), &new.into_js_value())
-                .unwrap_throw();
-        });
-        sauron::dom::register_web_component(
-            "sfui-frame",
-            constructor.into_js_value(),
-            Self::observed_attributes(),
-        );
+/// the number of `sfui-frame` elements the element is nested in
+pub(crate) fn nesting_depth(element: &web_sys::Element) -> usize {
+    let mut depth = 0;
+    let mut parent = element.parent_element();
+    while let Some(ancestor) = parent {
+        if ancestor.tag_name().eq_ignore_ascii_case(COMPONENT_NAME) {
+            depth += 1;
+        }
+        parent = ancestor.parent_element();
     }
+    depth
 }
 
-pub fn register() {
-    FrameCustomElement::register()
-}
+register_custom_element!(COMPONENT_NAME, FrameCustomElement, Frame<()>, Msg<()>);
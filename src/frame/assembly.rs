@@ -0,0 +1,94 @@
+use crate::custom_element::AttributeValues;
+use std::str::FromStr;
+
+/// The "draw-in" animation of a frame: the borders extend from the corners,
+/// then the corners snap in and then the content fades in.
+/// Disassembling plays the same sequence in reverse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Assembly {
+    /// the duration of each of the phases in ms
+    pub duration_ms: usize,
+    /// the delay between the start of the borders, corners and content phases in ms
+    pub stagger_ms: usize,
+    /// what starts the animation
+    pub trigger: AssemblyTrigger,
+}
+
+/// What starts the assembly of a frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssemblyTrigger {
+    /// as soon as the content of the frame is mounted
+    Mount,
+    /// when the frame scrolls into view
+    Visible,
+    /// only when `Msg::Assemble` is sent to the frame
+    Manual,
+}
+
+/// The state of the assembly animation of a frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssemblyState {
+    Disassembled,
+    Assembling,
+    Assembled,
+    Disassembling,
+}
+
+impl Default for Assembly {
+    fn default() -> Self {
+        Self {
+            duration_ms: 250,
+            stagger_ms: 150,
+            trigger: AssemblyTrigger::Mount,
+        }
+    }
+}
+
+impl Assembly {
+    pub fn on_mount() -> Self {
+        Self::default()
+    }
+
+    pub fn on_visible() -> Self {
+        Self {
+            trigger: AssemblyTrigger::Visible,
+            ..Default::default()
+        }
+    }
+
+    pub fn manual() -> Self {
+        Self {
+            trigger: AssemblyTrigger::Manual,
+            ..Default::default()
+        }
+    }
+
+    /// the time it takes for the whole sequence to complete
+    pub fn total_duration_ms(&self) -> usize {
+        self.duration_ms + self.stagger_ms * 2
+    }
+}
+
+impl FromStr for AssemblyTrigger {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "mount" => Ok(AssemblyTrigger::Mount),
+            "visible" => Ok(AssemblyTrigger::Visible),
+            "manual" => Ok(AssemblyTrigger::Manual),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for AssemblyTrigger {
+    const VALUES: &'static [&'static str] = &["mount", "visible", "manual"];
+}
+
+impl AssemblyState {
+    /// whether the frame is, or is heading to be fully drawn
+    pub fn is_assembled(&self) -> bool {
+        matches!(self, AssemblyState::Assembling | AssemblyState::Assembled)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::custom_element::AttributeValues;
+use crate::Theme;
+use std::str::FromStr;
+
+/// The backdrop drawn behind the content of a frame, generated from the theme colors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Background {
+    /// translucent fill using the content background color of the theme
+    Fill,
+    /// horizontal lines, like an old crt monitor
+    Scanlines,
+    /// blueprint grid
+    Grid,
+    /// dot matrix
+    Dots,
+    /// glow radiating from the center of the frame
+    Glow,
+}
+
+impl FromStr for Background {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "fill" => Ok(Background::Fill),
+            "scanlines" => Ok(Background::Scanlines),
+            "grid" => Ok(Background::Grid),
+            "dots" => Ok(Background::Dots),
+            "glow" => Ok(Background::Glow),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for Background {
+    const VALUES: &'static [&'static str] = &["fill", "scanlines", "grid", "dots", "glow"];
+}
+
+impl Background {
+    /// the css `background` and `background-size` of this backdrop
+    pub(crate) fn css(&self, theme: &Theme) -> (String, &'static str) {
+        let base = &theme.controls;
+        let line = &base.border_color;
+        match self {
+            Background::Fill => (base.content_background_color.clone(), "auto"),
+            Background::Scanlines => (
+                format!(
+                    "repeating-linear-gradient(to bottom, {line} 0px, {line} 1px, transparent 1px, transparent 3px)"
+                ),
+                "auto",
+            ),
+            Background::Grid => (
+                format!(
+                    "linear-gradient({line} 1px, transparent 1px), linear-gradient(90deg, {line} 1px, transparent 1px)"
+                ),
+                "20px 20px",
+            ),
+            Background::Dots => (
+                format!("radial-gradient({line} 1px, transparent 1.5px)"),
+                "8px 8px",
+            ),
+            Background::Glow => (
+                format!(
+                    "radial-gradient(ellipse at center, {} 0%, transparent 70%)",
+                    theme.accent_color
+                ),
+                "auto",
+            ),
+        }
+    }
+}
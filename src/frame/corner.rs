@@ -0,0 +1,87 @@
+use crate::custom_element::AttributeValues;
+use std::str::FromStr;
+
+/// The decoration drawn at a corner of the frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CornerStyle {
+    /// L-shaped bracket, this is the default
+    Bracket,
+    /// a round dot
+    Dot,
+    /// a solid triangle filling the corner
+    Triangle,
+    /// L-shaped bracket drawn with two parallel lines
+    DoubleLine,
+    /// a diagonal line cutting across the corner
+    Diagonal,
+    /// a small square marker which blinks
+    Blink,
+}
+
+/// The decoration of each of the four corners of a frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CornerStyles {
+    pub top_left: CornerStyle,
+    pub top_right: CornerStyle,
+    pub bottom_right: CornerStyle,
+    pub bottom_left: CornerStyle,
+}
+
+impl Default for CornerStyle {
+    fn default() -> Self {
+        CornerStyle::Bracket
+    }
+}
+
+impl Default for CornerStyles {
+    fn default() -> Self {
+        Self::all(CornerStyle::default())
+    }
+}
+
+impl CornerStyles {
+    /// the same decoration on all the corners
+    pub fn all(style: CornerStyle) -> Self {
+        Self {
+            top_left: style,
+            top_right: style,
+            bottom_right: style,
+            bottom_left: style,
+        }
+    }
+}
+
+impl CornerStyle {
+    /// the class name of the corner element with this decoration
+    pub(crate) fn class_name(&self) -> &'static str {
+        match self {
+            CornerStyle::Bracket => "corner--bracket",
+            CornerStyle::Dot => "corner--dot",
+            CornerStyle::Triangle => "corner--triangle",
+            CornerStyle::DoubleLine => "corner--double",
+            CornerStyle::Diagonal => "corner--diagonal",
+            CornerStyle::Blink => "corner--blink",
+        }
+    }
+}
+
+impl FromStr for CornerStyle {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "bracket" => Ok(CornerStyle::Bracket),
+            "dot" => Ok(CornerStyle::Dot),
+            "triangle" => Ok(CornerStyle::Triangle),
+            "double" => Ok(CornerStyle::DoubleLine),
+            "diagonal" => Ok(CornerStyle::Diagonal),
+            "blink" => Ok(CornerStyle::Blink),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for CornerStyle {
+    const VALUES: &'static [&'static str] =
+        &["bracket", "dot", "triangle", "double", "diagonal", "blink"];
+}
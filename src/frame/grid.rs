@@ -0,0 +1,96 @@
+use crate::scope::Scope;
+use sauron::{html::attributes::*, html::*, *};
+
+const COMPONENT_NAME: &str = "sfui-frame-grid";
+
+/// Lays out panels, usually frames, in a grid for hud style dashboards.
+/// Adjacent panels can share their borders, so the grid reads as one frame divided into panels.
+///
+/// ```ignore
+/// let grid = FrameGrid::new(2).with_shared_borders();
+/// grid.view(panels.iter().map(|panel| panel.view([]).map_msg(Msg::PanelMsg)))
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameGrid {
+    /// the number of panels in each row
+    pub columns: usize,
+    /// the space between the panels, ignored when the borders are shared
+    pub gap: i32,
+    /// the borders of adjacent panels are drawn on top of each other
+    pub shared_borders: bool,
+    scope: Scope,
+}
+
+impl Default for FrameGrid {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl FrameGrid {
+    pub fn new(columns: usize) -> Self {
+        Self {
+            columns: columns.max(1),
+            gap: 10,
+            shared_borders: false,
+            scope: Scope::new(COMPONENT_NAME),
+        }
+    }
+
+    pub fn with_gap(mut self, gap: i32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn with_shared_borders(mut self) -> Self {
+        self.shared_borders = true;
+        self
+    }
+
+    /// place each of the panels in a cell of the grid, row by row
+    pub fn view<MSG>(&self, panels: impl IntoIterator<Item = Node<MSG>>) -> Node<MSG> {
+        div(
+            [
+                class(COMPONENT_NAME),
+                class(self.scope.class("frame_grid")),
+                classes_flag([(self.scope.class("shared_borders"), self.shared_borders)]),
+            ],
+            panels
+                .into_iter()
+                .map(|panel| div([class(self.scope.class("cell"))], [panel])),
+        )
+    }
+
+    pub fn style(&self) -> Vec<String> {
+        let gap = if self.shared_borders { 0 } else { self.gap };
+        let main = jss! {
+            ".frame_grid": {
+                display: "grid",
+                grid_template_columns: format!("repeat({}, 1fr)", self.columns),
+                gap: px(gap),
+            },
+
+            ".frame_grid .cell": {
+                display: "flex",
+                min_width: 0,
+            },
+
+            // the panels fill their cell, instead of keeping their own margin
+            ".frame_grid .cell > *": {
+                flex: 1,
+                margin: 0,
+            },
+
+            // the margin of `sfui-frame` is set inside its shadow root
+            ".frame_grid .cell > *::part(frame)": {
+                margin: 0,
+            },
+
+            // overlap the one pixel frame of the adjacent panels
+            ".frame_grid.shared_borders .cell": {
+                margin: px([0, 0, -1, -1]),
+            },
+        };
+        vec![self.scope.style(&main)]
+    }
+}
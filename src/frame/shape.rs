@@ -0,0 +1,484 @@
+use crate::custom_element::AttributeValues;
+use std::str::FromStr;
+
+/// The outline of a frame rendered as an svg polygon
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shape {
+    /// the corners which are chipped off diagonally
+    pub chipped_corners: Corners,
+    /// the length of the chipped corner along each of its sides
+    pub chip_size: f32,
+    /// the sides which have a notch cut in the middle
+    pub notched_sides: Sides,
+    /// width of the notch at the edge of the side
+    pub notch_width: f32,
+    /// how deep the notch is cut into the frame
+    pub notch_depth: f32,
+    /// the left and right ends of the frame are pointed, like a hexagon.
+    /// Chipped corners and notches on the left and right sides are ignored
+    pub hexagonal_ends: bool,
+    /// draw a second outline inset from the main outline, giving a beveled edge
+    pub beveled: bool,
+    /// distance of the bevel outline from the main outline
+    pub bevel_inset: f32,
+}
+
+/// Selects any of the four corners of a frame
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Corners {
+    pub top_left: bool,
+    pub top_right: bool,
+    pub bottom_right: bool,
+    pub bottom_left: bool,
+}
+
+/// Selects any of the four sides of a frame
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Sides {
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+    pub left: bool,
+}
+
+impl Corners {
+    pub fn all() -> Self {
+        Self {
+            top_left: true,
+            top_right: true,
+            bottom_right: true,
+            bottom_left: true,
+        }
+    }
+
+    pub fn none() -> Self {
+        Self::default()
+    }
+}
+
+impl Sides {
+    pub fn all() -> Self {
+        Self {
+            top: true,
+            right: true,
+            bottom: true,
+            left: true,
+        }
+    }
+
+    pub fn none() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self {
+            chipped_corners: Corners::none(),
+            chip_size: 12.0,
+            notched_sides: Sides::none(),
+            notch_width: 40.0,
+            notch_depth: 4.0,
+            hexagonal_ends: false,
+            beveled: false,
+            bevel_inset: 4.0,
+        }
+    }
+}
+
+impl FromStr for Shape {
+    type Err = ();
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        match v {
+            "rectangle" => Ok(Shape::default()),
+            "chipped" => Ok(Shape::chipped(Corners {
+                bottom_right: true,
+                ..Corners::none()
+            })),
+            "chipped-diagonal" => Ok(Shape::chipped(Corners {
+                top_left: true,
+                bottom_right: true,
+                ..Corners::none()
+            })),
+            "beveled" => Ok(Shape::beveled()),
+            "notched" => Ok(Shape::notched(Sides {
+                top: true,
+                bottom: true,
+                ..Sides::none()
+            })),
+            "hexagon" => Ok(Shape::hexagon()),
+            _ => Err(()),
+        }
+    }
+}
+
+impl AttributeValues for Shape {
+    const VALUES: &'static [&'static str] = &[
+        "rectangle",
+        "chipped",
+        "chipped-diagonal",
+        "beveled",
+        "notched",
+        "hexagon",
+    ];
+}
+
+impl Shape {
+    /// rectangle with the selected corners chipped off
+    pub fn chipped(corners: Corners) -> Self {
+        Self {
+            chipped_corners: corners,
+            ..Default::default()
+        }
+    }
+
+    /// all the corners are chipped with an inner outline following the edges
+    pub fn beveled() -> Self {
+        Self {
+            chipped_corners: Corners::all(),
+            chip_size: 8.0,
+            beveled: true,
+            ..Default::default()
+        }
+    }
+
+    /// rectangle with a notch in the middle of the selected sides
+    pub fn notched(sides: Sides) -> Self {
+        Self {
+            notched_sides: sides,
+            ..Default::default()
+        }
+    }
+
+    /// the left and right ends are pointed
+    pub fn hexagon() -> Self {
+        Self {
+            hexagonal_ends: true,
+            ..Default::default()
+        }
+    }
+
+    /// the points of the outline, clockwise starting from the top left
+    pub fn points(&self, width: f32, height: f32) -> Vec<(f32, f32)> {
+        let (w, h) = (width, height);
+        // the chip can not be larger than half of the shortest side
+        let c = self.chip_size.min(w / 2.0).min(h / 2.0).max(0.0);
+        let tip = (h / 2.0).min(w / 2.0);
+        let corners = &self.chipped_corners;
+        let sides = &self.notched_sides;
+        let hex = self.hexagonal_ends;
+        // the room taken by the corners at the ends of each side
+        let chip = |chipped: bool| if chipped { c } else { 0.0 };
+        let (half_notch, d) = if hex {
+            self.notch(w, h, tip)
+        } else {
+            self.notch(
+                w,
+                h,
+                chip(corners.top_left || corners.bottom_left)
+                    .max(chip(corners.top_right || corners.bottom_right)),
+            )
+        };
+        let (half_side_notch, side_d) = self.notch(
+            h,
+            w,
+            chip(corners.top_left || corners.top_right)
+                .max(chip(corners.bottom_left || corners.bottom_right)),
+        );
+
+        let mut p = vec![];
+        // top left
+        if hex {
+            p.extend([(0.0, h / 2.0), (tip, 0.0)]);
+        } else if corners.top_left {
+            p.extend([(0.0, c), (c, 0.0)]);
+        } else {
+            p.push((0.0, 0.0));
+        }
+        // top side
+        if sides.top {
+            let mid = w / 2.0;
+            p.extend([
+                (mid - half_notch, 0.0),
+                (mid - half_notch + d, d),
+                (mid + half_notch - d, d),
+                (mid + half_notch, 0.0),
+            ]);
+        }
+        // top right
+        if hex {
+            p.extend([(w - tip, 0.0), (w, h / 2.0)]);
+        } else if corners.top_right {
+            p.extend([(w - c, 0.0), (w, c)]);
+        } else {
+            p.push((w, 0.0));
+        }
+        // right side
+        if sides.right && !hex {
+            let mid = h / 2.0;
+            p.extend([
+                (w, mid - half_side_notch),
+                (w - side_d, mid - half_side_notch + side_d),
+                (w - side_d, mid + half_side_notch - side_d),
+                (w, mid + half_side_notch),
+            ]);
+        }
+        // bottom right
+        if hex {
+            p.push((w - tip, h));
+        } else if corners.bottom_right {
+            p.extend([(w, h - c), (w - c, h)]);
+        } else {
+            p.push((w, h));
+        }
+        // bottom side
+        if sides.bottom {
+            let mid = w / 2.0;
+            p.extend([
+                (mid + half_notch, h),
+                (mid + half_notch - d, h - d),
+                (mid - half_notch + d, h - d),
+                (mid - half_notch, h),
+            ]);
+        }
+        // bottom left
+        if hex {
+            p.push((tip, h));
+        } else if corners.bottom_left {
+            p.extend([(c, h), (0.0, h - c)]);
+        } else {
+            p.push((0.0, h));
+        }
+        // left side
+        if sides.left && !hex {
+            let mid = h / 2.0;
+            p.extend([
+                (0.0, mid + half_side_notch),
+                (side_d, mid + half_side_notch - side_d),
+                (side_d, mid - half_side_notch + side_d),
+                (0.0, mid - half_side_notch),
+            ]);
+        }
+        p
+    }
+
+    /// the points of the outline as an open path, leaving a gap in the top side
+    /// between `gap.0` and `gap.1` from the left, such as for a title.
+    /// The path runs clockwise from the end of the gap around to its start
+    pub fn points_with_gap(&self, width: f32, height: f32, gap: (f32, f32)) -> Vec<(f32, f32)> {
+        let points = self.points(width, height);
+        let n = points.len();
+        let (start, end) = (gap.0.max(0.0), gap.1.min(width));
+        // the edge along the top side which the gap starts in
+        let edge = (0..n).find(|&i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            a.1 == 0.0 && b.1 == 0.0 && a.0 <= start && start < b.0
+        });
+        match edge {
+            Some(i) if start < end => {
+                let end = end.min(points[(i + 1) % n].0);
+                let mut p = vec![(end, 0.0)];
+                p.extend((1..=n).map(|k| points[(i + k) % n]));
+                p.push((start, 0.0));
+                p
+            }
+            // the outline is closed when the gap is not on the top side
+            _ => {
+                let first = points[0];
+                let mut p = points;
+                p.push(first);
+                p
+            }
+        }
+    }
+
+    /// the half width and the depth of a notch cut into a side of `length`, fitting between
+    /// the corners which take `margin` at each end of the side, and no deeper than half of
+    /// the `breadth` of the frame across that side
+    fn notch(&self, length: f32, breadth: f32, margin: f32) -> (f32, f32) {
+        let half_notch = (self.notch_width / 2.0).min(length / 2.0 - margin).max(0.0);
+        let depth = self.notch_depth.min(half_notch).min(breadth / 2.0).max(0.0);
+        (half_notch, depth)
+    }
+
+    /// the points of the bevel outline, which is the same shape inset by `bevel_inset`
+    pub fn bevel_points(&self, width: f32, height: f32) -> Vec<(f32, f32)> {
+        let inset = self.bevel_inset;
+        let inner = Self {
+            chip_size: (self.chip_size - inset / 2.0).max(0.0),
+            notch_width: (self.notch_width - inset).max(0.0),
+            ..*self
+        };
+        inner
+            .points(
+                (width - inset * 2.0).max(0.0),
+                (height - inset * 2.0).max(0.0),
+            )
+            .into_iter()
+            .map(|(x, y)| (x + inset, y + inset))
+            .collect()
+    }
+}
+
+/// format the points for the svg `points` attribute
+pub(crate) fn points_str(points: &[(f32, f32)]) -> String {
+    points
+        .iter()
+        .map(|p| format!("{},{}", p.0, p.1))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_within(points: &[(f32, f32)], width: f32, height: f32) {
+        for &(x, y) in points {
+            assert!(
+                (0.0..=width).contains(&x) && (0.0..=height).contains(&y),
+                "({}, {}) is outside of {}x{}",
+                x,
+                y,
+                width,
+                height
+            );
+        }
+    }
+
+    #[test]
+    fn rectangle() {
+        assert_eq!(
+            Shape::default().points(100.0, 50.0),
+            vec![(0.0, 0.0), (100.0, 0.0), (100.0, 50.0), (0.0, 50.0)]
+        );
+    }
+
+    #[test]
+    fn chipped_corner() {
+        let shape = Shape::chipped(Corners {
+            bottom_right: true,
+            ..Corners::none()
+        });
+        assert_eq!(
+            shape.points(100.0, 50.0),
+            vec![
+                (0.0, 0.0),
+                (100.0, 0.0),
+                (100.0, 38.0),
+                (88.0, 50.0),
+                (0.0, 50.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn chip_is_clamped_to_the_shortest_side() {
+        let points = Shape::beveled().points(10.0, 6.0);
+        assert_eq!(&points[..2], &[(0.0, 3.0), (3.0, 0.0)]);
+        assert_within(&points, 10.0, 6.0);
+    }
+
+    #[test]
+    fn notch() {
+        let shape = Shape::notched(Sides {
+            top: true,
+            ..Sides::none()
+        });
+        assert_eq!(
+            shape.points(100.0, 50.0),
+            vec![
+                (0.0, 0.0),
+                (30.0, 0.0),
+                (34.0, 4.0),
+                (66.0, 4.0),
+                (70.0, 0.0),
+                (100.0, 0.0),
+                (100.0, 50.0),
+                (0.0, 50.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn notch_is_clamped_to_the_side() {
+        let shape = Shape {
+            chipped_corners: Corners::all(),
+            chip_size: 4.0,
+            ..Shape::notched(Sides::all())
+        };
+        for (width, height) in [(20.0, 100.0), (100.0, 20.0), (6.0, 6.0), (0.0, 0.0)] {
+            assert_within(&shape.points(width, height), width, height);
+        }
+        // the notch fits between the chipped corners of the top side
+        let points = shape.points(20.0, 100.0);
+        assert_eq!(
+            &points[2..6],
+            &[(4.0, 0.0), (8.0, 4.0), (12.0, 4.0), (16.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn notch_depth_is_clamped_to_the_breadth() {
+        let shape = Shape {
+            notch_depth: 30.0,
+            ..Shape::notched(Sides::all())
+        };
+        assert_within(&shape.points(100.0, 10.0), 100.0, 10.0);
+    }
+
+    #[test]
+    fn hexagon() {
+        let shape = Shape::hexagon();
+        assert_eq!(
+            shape.points(100.0, 20.0),
+            vec![
+                (0.0, 10.0),
+                (10.0, 0.0),
+                (90.0, 0.0),
+                (100.0, 10.0),
+                (90.0, 20.0),
+                (10.0, 20.0)
+            ]
+        );
+        assert_within(&shape.points(10.0, 40.0), 10.0, 40.0);
+    }
+
+    #[test]
+    fn gap_in_the_top_side() {
+        assert_eq!(
+            Shape::default().points_with_gap(100.0, 50.0, (20.0, 60.0)),
+            vec![
+                (60.0, 0.0),
+                (100.0, 0.0),
+                (100.0, 50.0),
+                (0.0, 50.0),
+                (0.0, 0.0),
+                (20.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn gap_ends_before_the_chipped_corner() {
+        let shape = Shape::chipped(Corners::all());
+        let points = shape.points_with_gap(100.0, 50.0, (20.0, 95.0));
+        assert_eq!(points.first(), Some(&(88.0, 0.0)));
+        assert_eq!(points.last(), Some(&(20.0, 0.0)));
+    }
+
+    #[test]
+    fn gap_outside_of_the_top_side_is_closed() {
+        let points = Shape::default().points_with_gap(100.0, 50.0, (120.0, 140.0));
+        assert_eq!(points.len(), 5);
+        assert_eq!(points.first(), points.last());
+    }
+
+    #[test]
+    fn bevel_is_inside_the_outline() {
+        let shape = Shape::beveled();
+        assert_within(&shape.bevel_points(100.0, 50.0), 100.0, 50.0);
+        assert_within(&shape.bevel_points(4.0, 4.0), 4.0, 4.0);
+    }
+}
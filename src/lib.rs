@@ -5,16 +5,20 @@ pub use sauron;
 pub use status::Status;
 pub use theme::Theme;
 
+pub mod animation;
 pub mod button;
 pub mod card;
+pub mod custom_element;
 pub mod dice;
 pub mod frame;
+pub mod manifest;
+pub mod observer;
+pub mod scope;
 mod status;
 mod theme;
 
 pub fn register_all() {
-    button::register();
-    frame::register();
-    dice::register();
-    card::register();
+    for (register, _) in manifest::registered_elements() {
+        register();
+    }
 }
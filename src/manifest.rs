@@ -0,0 +1,134 @@
+//! Machine readable description of the `sfui-*` custom elements.
+//!
+//! This is derived from the declared attributes of each component and is used by
+//! `cargo xtask manifest` to generate the typescript declarations and the editor custom data.
+use crate::animation::ANIMATION_FRAME_EVENT;
+use crate::button::{self, Button};
+use crate::card::icon::{self, IconCard};
+use crate::card::product::{self, ProductCard};
+use crate::card::{self, Card};
+use crate::custom_element::{AttributeKind, DeclaredAttributes, THEME_PROPERTY_KEYS};
+use crate::dice::{self, Dice};
+use crate::frame::{self, Frame};
+use crate::observer::{RESIZE_EVENT, VISIBLE_EVENT};
+
+/// Description of a custom element
+#[derive(Debug, Clone)]
+pub struct ElementManifest {
+    /// the tag name, ie: `sfui-button`
+    pub tag: &'static str,
+    pub description: &'static str,
+    pub attributes: Vec<AttributeManifest>,
+    /// the custom events which the element dispatches on itself, the native dom events
+    /// such as `click` are not listed
+    pub events: &'static [&'static str],
+}
+
+/// Description of an attribute of a custom element
+#[derive(Debug, Clone)]
+pub struct AttributeManifest {
+    /// the html attribute name, ie: `theme-primary`
+    pub name: &'static str,
+    /// the js property name, ie: `themePrimary`
+    pub property: String,
+    pub kind: AttributeKind,
+    /// the allowed values, empty if the attribute accepts any value
+    pub values: &'static [&'static str],
+}
+
+impl ElementManifest {
+    fn new<APP>(
+        tag: &'static str,
+        description: &'static str,
+        events: &'static [&'static str],
+    ) -> Self
+    where
+        APP: DeclaredAttributes,
+    {
+        Self {
+            tag,
+            description,
+            attributes: APP::attributes()
+                .iter()
+                .map(|attr| AttributeManifest {
+                    name: attr.name,
+                    property: attr.property_name(),
+                    kind: attr.kind,
+                    values: attr.values,
+                })
+                .collect(),
+            events,
+        }
+    }
+
+    /// whether the element has the `theme` js property, which accepts `{primary, background}`
+    pub fn has_theme_property(&self) -> bool {
+        THEME_PROPERTY_KEYS
+            .iter()
+            .all(|(_, name)| self.attributes.iter().any(|attr| attr.name == *name))
+    }
+}
+
+/// the register function of each custom element along with its manifest,
+/// `register_all` and `elements` are both derived from this table
+pub(crate) fn registered_elements() -> Vec<(fn(), ElementManifest)> {
+    vec![
+        (
+            button::register,
+            ElementManifest::new::<Button<()>>(
+                button::COMPONENT_NAME,
+                "A futuristic button with corners, underline and click highlights",
+                &[],
+            ),
+        ),
+        (
+            frame::register,
+            ElementManifest::new::<Frame<()>>(
+                frame::COMPONENT_NAME,
+                "A frame with borders and corners around its content",
+                &[RESIZE_EVENT, VISIBLE_EVENT],
+            ),
+        ),
+        (
+            dice::register,
+            ElementManifest::new::<Dice<()>>(
+                dice::COMPONENT_NAME,
+                "Reveals and hides its content slice by slice, when clicked, mounted or scrolled into view",
+                &[
+                    RESIZE_EVENT,
+                    VISIBLE_EVENT,
+                    ANIMATION_FRAME_EVENT,
+                    dice::ANIMATION_END_EVENT,
+                ],
+            ),
+        ),
+        (
+            card::register,
+            ElementManifest::new::<Card<()>>(card::COMPONENT_NAME, "A card wrapped in a frame", &[]),
+        ),
+        (
+            product::register,
+            ElementManifest::new::<ProductCard<()>>(
+                product::COMPONENT_NAME,
+                "A product card with an image, name, description, price and an add to cart button",
+                &[],
+            ),
+        ),
+        (
+            icon::register,
+            ElementManifest::new::<IconCard<()>>(
+                icon::COMPONENT_NAME,
+                "A card with a large svg icon, a heading and a short text",
+                &[],
+            ),
+        ),
+    ]
+}
+
+/// all the custom elements registered with `register_all`
+pub fn elements() -> Vec<ElementManifest> {
+    registered_elements()
+        .into_iter()
+        .map(|(_, manifest)| manifest)
+        .collect()
+}
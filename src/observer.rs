@@ -0,0 +1,86 @@
+//! Wrappers of the browser observers which report back to the components through dom events,
+//! so the components can listen to them in their view like any other event.
+use sauron::wasm_bindgen::JsCast;
+use sauron::*;
+
+/// the event dispatched on an observed element each time its size changes
+pub const RESIZE_EVENT: &str = "sfui-resize";
+
+/// Observes the size of an element and dispatches [`RESIZE_EVENT`] on it when it changes.
+/// The observer is disconnected when dropped.
+#[derive(Debug)]
+pub struct SizeObserver {
+    observer: web_sys::ResizeObserver,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl SizeObserver {
+    pub fn observe(element: &web_sys::Element) -> Self {
+        let target = element.clone();
+        let callback: Closure<dyn FnMut(js_sys::Array)> = Closure::new(move |_entries| {
+            let event = web_sys::Event::new(RESIZE_EVENT).expect("must create event");
+            target.dispatch_event(&event).expect("must dispatch event");
+        });
+        let observer = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref())
+            .expect("must create resize observer");
+        observer.observe(element);
+        Self {
+            observer,
+            _callback: callback,
+        }
+    }
+
+    /// observe the size of another element too,
+    /// the event is still dispatched on the element which was first observed
+    pub fn observe_also(&self, element: &web_sys::Element) {
+        self.observer.observe(element);
+    }
+}
+
+impl Drop for SizeObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// the event dispatched on an observed element each time it scrolls into view
+pub const VISIBLE_EVENT: &str = "sfui-visible";
+
+/// Observes the visibility of an element in the viewport and dispatches [`VISIBLE_EVENT`]
+/// on it each time it becomes visible. The observer is disconnected when dropped.
+#[derive(Debug)]
+pub struct VisibilityObserver {
+    observer: web_sys::IntersectionObserver,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl VisibilityObserver {
+    pub fn observe(element: &web_sys::Element) -> Self {
+        let target = element.clone();
+        let callback: Closure<dyn FnMut(js_sys::Array)> =
+            Closure::new(move |entries: js_sys::Array| {
+                let is_visible = entries.iter().any(|entry| {
+                    entry
+                        .unchecked_into::<web_sys::IntersectionObserverEntry>()
+                        .is_intersecting()
+                });
+                if is_visible {
+                    let event = web_sys::Event::new(VISIBLE_EVENT).expect("must create event");
+                    target.dispatch_event(&event).expect("must dispatch event");
+                }
+            });
+        let observer = web_sys::IntersectionObserver::new(callback.as_ref().unchecked_ref())
+            .expect("must create intersection observer");
+        observer.observe(element);
+        Self {
+            observer,
+            _callback: callback,
+        }
+    }
+}
+
+impl Drop for VisibilityObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
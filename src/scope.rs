@@ -0,0 +1,181 @@
+//! Per instance namespacing of the class names used by the components.
+//!
+//! The styles of the components are injected as global stylesheets when they are used
+//! through the rust `Container` api, so the class names used in the view and the selectors
+//! in the style are prefixed with a namespace unique to each instance. This allows
+//! multiple instances with different themes and dimensions to coexist in the same page.
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static INSTANCE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// The namespace of the class names of a component instance
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scope {
+    namespace: String,
+}
+
+impl Scope {
+    /// create a new namespace, unique for each instance of the component `name`
+    pub fn new(name: &str) -> Self {
+        let id = INSTANCE_COUNT.fetch_add(1, Ordering::Relaxed);
+        Self {
+            namespace: format!("{}-{}", name, id),
+        }
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// prefix each of the space separated class names with the namespace
+    pub fn class(&self, class_names: &str) -> String {
+        class_names
+            .split_whitespace()
+            .map(|class_name| format!("{}__{}", self.namespace, class_name))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// prefix the class selectors in the rule preludes of the stylesheet with the namespace,
+    /// the declarations and quoted strings are left as is
+    pub fn style(&self, css: &str) -> String {
+        let mut scoped = String::with_capacity(css.len());
+        let mut pending = String::new();
+        let mut quote = None;
+        let mut chars = css.chars();
+        while let Some(ch) = chars.next() {
+            if let Some(q) = quote {
+                pending.push(ch);
+                if ch == '\\' {
+                    pending.extend(chars.next());
+                } else if ch == q {
+                    quote = None;
+                }
+                continue;
+            }
+            match ch {
+                '"' | '\'' => {
+                    quote = Some(ch);
+                    pending.push(ch);
+                }
+                '{' => {
+                    scoped.push_str(&self.selector(&pending));
+                    scoped.push(ch);
+                    pending.clear();
+                }
+                '}' | ';' => {
+                    scoped.push_str(&pending);
+                    scoped.push(ch);
+                    pending.clear();
+                }
+                _ => pending.push(ch),
+            }
+        }
+        scoped.push_str(&pending);
+        scoped
+    }
+
+    /// prefix the class selectors with the namespace,
+    /// the attribute selectors such as `[href$=".pdf"]` and quoted strings are left as is
+    pub fn selector(&self, selector: &str) -> String {
+        let mut scoped = String::with_capacity(selector.len());
+        let mut chars = selector.chars().peekable();
+        let mut quote = None;
+        let mut in_brackets = false;
+        while let Some(ch) = chars.next() {
+            scoped.push(ch);
+            if let Some(q) = quote {
+                if ch == '\\' {
+                    scoped.extend(chars.next());
+                } else if ch == q {
+                    quote = None;
+                }
+                continue;
+            }
+            match ch {
+                '"' | '\'' => quote = Some(ch),
+                '[' => in_brackets = true,
+                ']' => in_brackets = false,
+                // the dot in numbers such as in `(min-width: 0.5em)` is not a class selector
+                '.' if !in_brackets
+                    && chars
+                        .peek()
+                        .map(|next| next.is_alphabetic() || *next == '_' || *next == '-')
+                        .unwrap_or(false) =>
+                {
+                    scoped.push_str(&self.namespace);
+                    scoped.push_str("__");
+                }
+                _ => (),
+            }
+        }
+        scoped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope() -> Scope {
+        Scope {
+            namespace: "sfui-frame-0".to_string(),
+        }
+    }
+
+    #[test]
+    fn class_names() {
+        assert_eq!(
+            scope().class("border border-top"),
+            "sfui-frame-0__border sfui-frame-0__border-top"
+        );
+    }
+
+    #[test]
+    fn class_selectors() {
+        assert_eq!(
+            scope().selector(".frame:hover > .content_wrap, div:not(.hidden)"),
+            ".sfui-frame-0__frame:hover > .sfui-frame-0__content_wrap, div:not(.sfui-frame-0__hidden)"
+        );
+    }
+
+    #[test]
+    fn numbers_are_not_class_selectors() {
+        assert_eq!(
+            scope().selector("@media (min-width: 0.5em)"),
+            "@media (min-width: 0.5em)"
+        );
+    }
+
+    #[test]
+    fn attribute_selectors_are_left_as_is() {
+        assert_eq!(
+            scope().selector(r#".link[href$=".pdf"], a[class~=a.b] .icon"#),
+            r#".sfui-frame-0__link[href$=".pdf"], a[class~=a.b] .sfui-frame-0__icon"#
+        );
+    }
+
+    #[test]
+    fn quoted_strings_are_left_as_is() {
+        assert_eq!(
+            scope().selector(r#"a[title='it\'s .x'] .y"#),
+            r#"a[title='it\'s .x'] .sfui-frame-0__y"#
+        );
+    }
+
+    #[test]
+    fn declarations_are_left_as_is() {
+        assert_eq!(
+            scope().style(".frame{margin:0.5em;background:url(a.png);}"),
+            ".sfui-frame-0__frame{margin:0.5em;background:url(a.png);}"
+        );
+    }
+
+    #[test]
+    fn braces_in_quoted_strings() {
+        assert_eq!(
+            scope().style(r#".a::before{content:"{.b}";}.c[data-x="}"]{color:red;}"#),
+            r#".sfui-frame-0__a::before{content:"{.b}";}.sfui-frame-0__c[data-x="}"]{color:red;}"#
+        );
+    }
+}
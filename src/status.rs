@@ -1,3 +1,4 @@
+use crate::custom_element::AttributeValues;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy)]
@@ -32,3 +33,7 @@ impl FromStr for Status {
         }
     }
 }
+
+impl AttributeValues for Status {
+    const VALUES: &'static [&'static str] = &["error", "success", "info", "warning"];
+}
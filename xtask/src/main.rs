@@ -2,6 +2,8 @@ use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 use xshell::{cmd, Shell};
 
+mod manifest;
+
 const APP: &str = "dot";
 
 /// Simple program to greet a person
@@ -22,6 +24,13 @@ enum Commands {
         #[arg(short, long)]
         port: u16,
     },
+    /// Generate the custom elements manifest, vscode html custom data
+    /// and typescript declarations of the sfui-* elements
+    Manifest {
+        /// Directory where the generated files are written
+        #[arg(short, long, default_value = "pkg")]
+        out: PathBuf,
+    },
 }
 
 fn run_webapp(sh: &Shell, dir: &PathBuf, port: u16) -> anyhow::Result<()> {
@@ -41,6 +50,9 @@ fn main() -> anyhow::Result<()> {
         Commands::RunWeb { dir, port } => {
             run_webapp(&sh, &dir, port)?;
         }
+        Commands::Manifest { out } => {
+            manifest::generate(&out)?;
+        }
     }
     Ok(())
 }
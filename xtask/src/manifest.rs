@@ -0,0 +1,311 @@
+//! Generate the custom element manifests from the declared attributes of the sfui components
+use serde_json::{json, Value};
+use sfui::custom_element::AttributeKind;
+use sfui::manifest::{AttributeManifest, ElementManifest};
+use std::fs;
+use std::path::Path;
+
+/// the js module which exports the custom elements, as built by wasm-pack
+const MODULE_PATH: &str = "sfui.js";
+
+/// write `custom-elements.json`, `html-custom-data.json` and `sfui.d.ts` into `out_dir`
+pub fn generate(out_dir: &Path) -> anyhow::Result<()> {
+    let elements = sfui::manifest::elements();
+    fs::create_dir_all(out_dir)?;
+    fs::write(
+        out_dir.join("custom-elements.json"),
+        serde_json::to_string_pretty(&custom_elements_manifest(&elements))?,
+    )?;
+    fs::write(
+        out_dir.join("html-custom-data.json"),
+        serde_json::to_string_pretty(&html_custom_data(&elements))?,
+    )?;
+    fs::write(out_dir.join("sfui.d.ts"), type_declarations(&elements))?;
+    Ok(())
+}
+
+/// the typescript type of the attribute when accessed as a js property
+fn property_type(attr: &AttributeManifest) -> String {
+    match attr.kind {
+        AttributeKind::Boolean => "boolean".to_string(),
+        AttributeKind::Number => "number | null".to_string(),
+        AttributeKind::String if attr.values.is_empty() => "string | null".to_string(),
+        AttributeKind::String => {
+            let values = attr
+                .values
+                .iter()
+                .map(|v| format!("\"{}\"", v))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            format!("{} | null", values)
+        }
+    }
+}
+
+const THEME_TYPE: &str = "{ primary?: string; background?: string }";
+
+/// `sfui-frame` -> `SfuiFrameElement`
+fn interface_name(tag: &str) -> String {
+    let name = tag
+        .split('-')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<String>();
+    format!("{}Element", name)
+}
+
+/// https://github.com/webcomponents/custom-elements-manifest
+fn custom_elements_manifest(elements: &[ElementManifest]) -> Value {
+    let declarations = elements
+        .iter()
+        .map(|element| {
+            let attributes = element
+                .attributes
+                .iter()
+                .map(|attr| {
+                    json!({
+                        "name": attr.name,
+                        "fieldName": attr.property,
+                        "type": { "text": property_type(attr) },
+                    })
+                })
+                .collect::<Vec<_>>();
+            let mut members = element
+                .attributes
+                .iter()
+                .map(|attr| {
+                    json!({
+                        "kind": "field",
+                        "name": attr.property,
+                        "attribute": attr.name,
+                        "reflects": true,
+                        "type": { "text": property_type(attr) },
+                    })
+                })
+                .collect::<Vec<_>>();
+            if element.has_theme_property() {
+                members.push(json!({
+                    "kind": "field",
+                    "name": "theme",
+                    "type": { "text": THEME_TYPE },
+                }));
+            }
+            let events = element
+                .events
+                .iter()
+                .map(|event| json!({ "name": event }))
+                .collect::<Vec<_>>();
+            json!({
+                "kind": "class",
+                "name": interface_name(element.tag),
+                "tagName": element.tag,
+                "customElement": true,
+                "description": element.description,
+                "attributes": attributes,
+                "members": members,
+                "events": events,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let exports = elements
+        .iter()
+        .map(|element| {
+            json!({
+                "kind": "custom-element-definition",
+                "name": element.tag,
+                "declaration": {
+                    "name": interface_name(element.tag),
+                    "module": MODULE_PATH,
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "schemaVersion": "1.0.0",
+        "modules": [{
+            "kind": "javascript-module",
+            "path": MODULE_PATH,
+            "declarations": declarations,
+            "exports": exports,
+        }],
+    })
+}
+
+/// https://github.com/microsoft/vscode-custom-data
+fn html_custom_data(elements: &[ElementManifest]) -> Value {
+    let tags = elements
+        .iter()
+        .map(|element| {
+            let attributes = element
+                .attributes
+                .iter()
+                .map(|attr| {
+                    let mut attribute = json!({ "name": attr.name });
+                    if !attr.values.is_empty() {
+                        attribute["values"] =
+                            attr.values.iter().map(|v| json!({ "name": v })).collect();
+                    }
+                    if attr.kind == AttributeKind::Boolean {
+                        attribute["valueSet"] = json!("v");
+                    }
+                    attribute
+                })
+                .collect::<Vec<_>>();
+            json!({
+                "name": element.tag,
+                "description": element.description,
+                "attributes": attributes,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "version": 1.1,
+        "tags": tags,
+    })
+}
+
+fn type_declarations(elements: &[ElementManifest]) -> String {
+    let mut out = String::from("// generated by `cargo xtask manifest`, do not edit\n\n");
+    for element in elements {
+        out += &format!("/** {} */\n", element.description);
+        out += &format!(
+            "export interface {} extends HTMLElement {{\n",
+            interface_name(element.tag)
+        );
+        for attr in element.attributes.iter() {
+            out += &format!("    /** reflects the `{}` attribute */\n", attr.name);
+            out += &format!("    {}: {};\n", attr.property, property_type(attr));
+        }
+        if element.has_theme_property() {
+            out += &format!("    theme: {};\n", THEME_TYPE);
+        }
+        out += "}\n\n";
+    }
+    out += "declare global {\n    interface HTMLElementTagNameMap {\n";
+    for element in elements {
+        out += &format!(
+            "        \"{}\": {};\n",
+            element.tag,
+            interface_name(element.tag)
+        );
+    }
+    out += "    }\n}\n";
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button() -> ElementManifest {
+        ElementManifest {
+            tag: "sfui-button",
+            description: "A button",
+            attributes: vec![
+                AttributeManifest {
+                    name: "theme-primary",
+                    property: "themePrimary".to_string(),
+                    kind: AttributeKind::String,
+                    values: &[],
+                },
+                AttributeManifest {
+                    name: "theme-background",
+                    property: "themeBackground".to_string(),
+                    kind: AttributeKind::String,
+                    values: &[],
+                },
+                AttributeManifest {
+                    name: "status",
+                    property: "status".to_string(),
+                    kind: AttributeKind::String,
+                    values: &["error", "success"],
+                },
+                AttributeManifest {
+                    name: "disabled",
+                    property: "disabled".to_string(),
+                    kind: AttributeKind::Boolean,
+                    values: &[],
+                },
+            ],
+            events: &[],
+        }
+    }
+
+    #[test]
+    fn custom_elements_manifest_declares_the_element() {
+        let manifest = custom_elements_manifest(&[button()]);
+        let module = &manifest["modules"][0];
+        let declaration = &module["declarations"][0];
+        assert_eq!(declaration["name"], "SfuiButtonElement");
+        assert_eq!(declaration["tagName"], "sfui-button");
+        assert_eq!(declaration["attributes"][2]["fieldName"], "status");
+        assert_eq!(
+            declaration["attributes"][2]["type"]["text"],
+            "\"error\" | \"success\" | null"
+        );
+        assert_eq!(declaration["attributes"][3]["type"]["text"], "boolean");
+        let members = declaration["members"].as_array().unwrap();
+        assert_eq!(members.len(), 5);
+        assert_eq!(members[4]["name"], "theme");
+        assert_eq!(declaration["events"], json!([]));
+        assert_eq!(module["exports"][0]["name"], "sfui-button");
+        assert_eq!(
+            module["exports"][0]["declaration"]["name"],
+            "SfuiButtonElement"
+        );
+    }
+
+    #[test]
+    fn type_declarations_of_the_element() {
+        let declarations = type_declarations(&[button()]);
+        assert!(declarations.contains("export interface SfuiButtonElement extends HTMLElement {"));
+        assert!(declarations.contains("    themePrimary: string | null;\n"));
+        assert!(declarations.contains("    status: \"error\" | \"success\" | null;\n"));
+        assert!(declarations.contains("    disabled: boolean;\n"));
+        assert!(declarations.contains("    theme: { primary?: string; background?: string };\n"));
+        assert!(declarations.contains("        \"sfui-button\": SfuiButtonElement;\n"));
+    }
+
+    #[test]
+    fn registered_elements_have_unique_sfui_tags() {
+        let elements = sfui::manifest::elements();
+        let mut tags: Vec<&str> = elements.iter().map(|element| element.tag).collect();
+        assert!(tags.iter().all(|tag| tag.starts_with("sfui-")));
+        tags.sort_unstable();
+        tags.dedup();
+        assert_eq!(tags.len(), elements.len());
+    }
+
+    #[test]
+    fn observed_events_are_declared() {
+        let elements = sfui::manifest::elements();
+        let events = |tag: &str| {
+            elements
+                .iter()
+                .find(|element| element.tag == tag)
+                .map(|element| element.events)
+                .unwrap()
+        };
+        assert!(events("sfui-frame").contains(&"sfui-resize"));
+        assert!(events("sfui-frame").contains(&"sfui-visible"));
+        assert!(events("sfui-dice").contains(&"sfui-animation-frame"));
+        assert!(events("sfui-dice").contains(&"sfui-animation-end"));
+
+        let manifest = custom_elements_manifest(&elements);
+        let dice = manifest["modules"][0]["declarations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|declaration| declaration["tagName"] == "sfui-dice")
+            .unwrap();
+        assert_eq!(dice["events"].as_array().unwrap().len(), 4);
+    }
+}